@@ -3,10 +3,13 @@ use crate::aoc::year::Year;
 use std::fmt::{Display, Formatter};
 use std::fs;
 use std::fs::read_to_string;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
 
 enum ResourceType {
     Inputs,
     Outputs,
+    Leaderboards,
     #[cfg(test)]
     Examples,
 }
@@ -16,6 +19,7 @@ impl Display for ResourceType {
         let resource_type = match self {
             ResourceType::Inputs => "inputs",
             ResourceType::Outputs => "outputs",
+            ResourceType::Leaderboards => "leaderboards",
             #[cfg(test)]
             ResourceType::Examples => "examples",
         };
@@ -47,24 +51,50 @@ pub fn read_output(day: &str, year: Year) -> ExpectedResult {
     ExpectedResult::from(content.unwrap_or_default())
 }
 
+pub fn write_leaderboard(id: &str, year: Year, data: &str) -> std::io::Result<()> {
+    let file_path = build_path(ResourceType::Leaderboards, id, year);
+
+    if let Some(dir) = Path::new(&file_path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(file_path, data)
+}
+
+/// Returns saved leaderboard only if it isn't older than `max_age`
+pub fn read_leaderboard(id: &str, year: Year, max_age: Duration) -> std::io::Result<String> {
+    let file_path = build_path(ResourceType::Leaderboards, id, year);
+    let modified = fs::metadata(&file_path)?.modified()?;
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or(Duration::ZERO);
+
+    if age > max_age {
+        return Err(std::io::Error::other("Saved leaderboard is outdated"));
+    }
+
+    read_to_string(file_path)
+}
+
 #[cfg(test)]
 pub fn read_example(day: &str, year: Year) -> String {
     read(ResourceType::Examples, day, year).unwrap()
 }
 
-fn read(resource_type: ResourceType, day: &str, year: Year) -> std::io::Result<String> {
-    let file_path = build_path(resource_type, day, year);
+fn read(resource_type: ResourceType, name: &str, year: Year) -> std::io::Result<String> {
+    let file_path = build_path(resource_type, name, year);
 
     read_to_string(file_path)
 }
 
-fn build_path(resource_type: ResourceType, day: &str, year: Year) -> String {
+fn build_path(resource_type: ResourceType, name: &str, year: Year) -> String {
     let format = match resource_type {
         ResourceType::Inputs => "in",
         ResourceType::Outputs => "out",
+        ResourceType::Leaderboards => "json",
         #[cfg(test)]
         ResourceType::Examples => "in",
     };
 
-    format!("resources/{}/{}/{}.{}", year, resource_type, day, format)
+    format!("resources/{}/{}/{}.{}", year, resource_type, name, format)
 }
@@ -0,0 +1,315 @@
+use itertools::Itertools;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// Puzzles unlock at midnight EST (UTC-5)
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+#[derive(Debug, PartialEq)]
+pub struct PrivateLeaderboard {
+    event: u16,
+    members: Vec<Member>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Member {
+    name: String,
+    stars: u64,
+    local_score: u64,
+    days: BTreeMap<u8, DayCompletion>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DayCompletion {
+    part_one: Option<u64>,
+    part_two: Option<u64>,
+}
+
+impl PrivateLeaderboard {
+    /// Members sorted by local score, then by stars
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+}
+
+impl Member {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn stars(&self) -> u64 {
+        self.stars
+    }
+
+    pub fn local_score(&self) -> u64 {
+        self.local_score
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayCompletion> {
+        self.days.get(&day)
+    }
+}
+
+impl DayCompletion {
+    /// Seconds elapsed from the puzzle unlock to getting the first star
+    pub fn part_one_time(&self, event: u16, day: u8) -> Option<u64> {
+        self.part_one
+            .map(|ts| ts.saturating_sub(unlock_timestamp(event, day)))
+    }
+
+    /// Seconds elapsed from the puzzle unlock to getting the second star
+    pub fn part_two_time(&self, event: u16, day: u8) -> Option<u64> {
+        self.part_two
+            .map(|ts| ts.saturating_sub(unlock_timestamp(event, day)))
+    }
+
+    /// Seconds elapsed between getting the first and the second star
+    pub fn delta(&self) -> Option<u64> {
+        match (self.part_one, self.part_two) {
+            (Some(one), Some(two)) => Some(two.saturating_sub(one)),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for PrivateLeaderboard {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json: Value =
+            serde_json::from_str(value).map_err(|e| format!("Invalid leaderboard JSON: {}", e))?;
+
+        let event = match &json["event"] {
+            Value::String(s) => s.parse().ok(),
+            Value::Number(n) => n.as_u64().map(|n| n as u16),
+            _ => None,
+        }
+        .ok_or("Missing or invalid `event` field".to_string())?;
+
+        let members = json["members"]
+            .as_object()
+            .ok_or("Missing `members` field".to_string())?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<Member>, String>>()?
+            .into_iter()
+            .sorted_by(|a, b| {
+                b.local_score
+                    .cmp(&a.local_score)
+                    .then(b.stars.cmp(&a.stars))
+                    .then(a.name.cmp(&b.name))
+            })
+            .collect();
+
+        Ok(Self { event, members })
+    }
+}
+
+impl TryFrom<&Value> for Member {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let id = value["id"]
+            .as_u64()
+            .ok_or("Member without `id`".to_string())?;
+        let name = value["name"]
+            .as_str()
+            .map(|s| s.to_string())
+            .unwrap_or(format!("(anonymous user #{})", id));
+        let stars = value["stars"].as_u64().unwrap_or(0);
+        let local_score = value["local_score"].as_u64().unwrap_or(0);
+
+        let mut days: BTreeMap<u8, DayCompletion> = BTreeMap::new();
+        if let Some(completion) = value["completion_day_level"].as_object() {
+            for (day, parts) in completion {
+                let day: u8 = day
+                    .parse()
+                    .map_err(|_| format!("Invalid day number `{}`", day))?;
+
+                days.insert(
+                    day,
+                    DayCompletion {
+                        part_one: parts["1"]["get_star_ts"].as_u64(),
+                        part_two: parts["2"]["get_star_ts"].as_u64(),
+                    },
+                );
+            }
+        }
+
+        Ok(Self {
+            name,
+            stars,
+            local_score,
+            days,
+        })
+    }
+}
+
+impl Display for PrivateLeaderboard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let last_day = self
+            .members
+            .iter()
+            .flat_map(|m| m.days.keys())
+            .max()
+            .copied()
+            .unwrap_or(0);
+
+        for (position, member) in self.members().iter().enumerate() {
+            writeln!(
+                f,
+                "{:>3}) {} - {} ⭐, {} points",
+                position + 1,
+                member.name(),
+                member.stars(),
+                member.local_score()
+            )?;
+
+            for day in 1..=last_day {
+                let Some(completion) = member.day(day) else {
+                    continue;
+                };
+
+                let line = format!(
+                    "     Day {:>2}: {:>12} {:>12} {:>14}",
+                    day,
+                    format_duration(completion.part_one_time(self.event, day)),
+                    format_duration(completion.part_two_time(self.event, day)),
+                    completion
+                        .delta()
+                        .map(|d| format!("(+{})", format_duration(Some(d))))
+                        .unwrap_or_default(),
+                );
+
+                writeln!(f, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_duration(seconds: Option<u64>) -> String {
+    match seconds {
+        None => "-".to_string(),
+        Some(seconds) => format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            (seconds % 3600) / 60,
+            seconds % 60
+        ),
+    }
+}
+
+/// Unix timestamp of the moment when a puzzle for the given day of December unlocks
+fn unlock_timestamp(year: u16, day: u8) -> u64 {
+    days_since_epoch(year as i64, 12, day as i64) as u64 * 86_400 + UNLOCK_HOUR_UTC * 3600
+}
+
+/// Number of days from 1970-01-01 to a given date in the proleptic Gregorian calendar
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aoc::leaderboard::{days_since_epoch, unlock_timestamp, PrivateLeaderboard};
+
+    const LEADERBOARD: &str = r#"{
+  "event": "2024",
+  "owner_id": 1,
+  "members": {
+    "1": {
+      "id": 1,
+      "name": "Alice",
+      "stars": 3,
+      "local_score": 10,
+      "global_score": 0,
+      "last_star_ts": 1733036400,
+      "completion_day_level": {
+        "1": {
+          "1": { "get_star_ts": 1733029500, "star_index": 1 },
+          "2": { "get_star_ts": 1733030100, "star_index": 2 }
+        },
+        "2": {
+          "1": { "get_star_ts": 1733122800, "star_index": 3 }
+        }
+      }
+    },
+    "2": {
+      "id": 2,
+      "name": null,
+      "stars": 1,
+      "local_score": 4,
+      "global_score": 0,
+      "last_star_ts": 1733029800,
+      "completion_day_level": {
+        "1": {
+          "1": { "get_star_ts": 1733029800, "star_index": 1 }
+        }
+      }
+    }
+  }
+}"#;
+
+    #[test]
+    fn parse() {
+        let leaderboard = PrivateLeaderboard::try_from(LEADERBOARD).unwrap();
+        let members = leaderboard.members();
+
+        assert_eq!(2, members.len());
+        assert_eq!("Alice", members[0].name());
+        assert_eq!(3, members[0].stars());
+        assert_eq!(10, members[0].local_score());
+        assert_eq!("(anonymous user #2)", members[1].name());
+    }
+
+    #[test]
+    fn completion_times() {
+        let leaderboard = PrivateLeaderboard::try_from(LEADERBOARD).unwrap();
+        let day_one = leaderboard.members()[0].day(1).unwrap();
+
+        assert_eq!(Some(300), day_one.part_one_time(2024, 1));
+        assert_eq!(Some(900), day_one.part_two_time(2024, 1));
+        assert_eq!(Some(600), day_one.delta());
+
+        let day_two = leaderboard.members()[0].day(2).unwrap();
+        assert_eq!(Some(7200), day_two.part_one_time(2024, 2));
+        assert_eq!(None, day_two.part_two_time(2024, 2));
+        assert_eq!(None, day_two.delta());
+    }
+
+    #[test]
+    fn display() {
+        let leaderboard = PrivateLeaderboard::try_from(LEADERBOARD).unwrap();
+
+        const EXPECTED: &str = r#"  1) Alice - 3 ⭐, 10 points
+     Day  1:     00:05:00     00:15:00    (+00:10:00)
+     Day  2:     02:00:00            -
+  2) (anonymous user #2) - 1 ⭐, 4 points
+     Day  1:     00:10:00            -
+"#;
+
+        assert_eq!(EXPECTED, leaderboard.to_string());
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert!(PrivateLeaderboard::try_from("{").is_err());
+        assert!(PrivateLeaderboard::try_from(r#"{"event": "2024"}"#).is_err());
+    }
+
+    #[test]
+    fn unlock_time() {
+        assert_eq!(0, days_since_epoch(1970, 1, 1));
+        assert_eq!(1_733_029_200, unlock_timestamp(2024, 1));
+        assert_eq!(1_764_565_200, unlock_timestamp(2025, 1));
+    }
+}
@@ -1,6 +1,7 @@
 pub mod day_number;
 pub mod expected_result;
 pub mod file_system;
+pub mod leaderboard;
 pub mod puzzle_day;
 pub mod puzzle_part;
 pub mod year;
@@ -22,3 +23,34 @@ pub fn client(day_number: DayNumber, year: Year) -> AocClient {
         .build()
         .unwrap()
 }
+
+/// The leaderboard endpoint isn't exposed by `aoc_client`, so it is fetched directly
+pub fn fetch_private_leaderboard(year: Year, id: u64) -> Result<String, String> {
+    let session = std::env::var("SESSION_COOKIE_ENV_VAR").unwrap();
+    let url = format!(
+        "https://adventofcode.com/{}/leaderboard/private/view/{}.json",
+        year, id
+    );
+
+    let response = reqwest::blocking::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .and_then(|client| {
+            client
+                .get(url)
+                .header(
+                    reqwest::header::COOKIE,
+                    format!("session={}", session.trim()),
+                )
+                .header(reqwest::header::USER_AGENT, env!("CARGO_PKG_NAME"))
+                .send()
+        })
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_redirection() {
+        return Err("The private leaderboard does not exist or you are not a member".to_string());
+    }
+
+    response.text().map_err(|e| e.to_string())
+}
@@ -0,0 +1,40 @@
+use crate::aoc::fetch_private_leaderboard;
+use crate::aoc::file_system::{read_leaderboard, write_leaderboard};
+use crate::aoc::leaderboard::PrivateLeaderboard;
+use crate::aoc::year::Year;
+use std::fs::read_to_string;
+use std::time::Duration;
+
+/// AoC asks to not request private leaderboard more often than once per 15 minutes
+const CACHE_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+pub fn show_leaderboard(id: u64, year: Year, file: Option<String>) {
+    let json = match file {
+        Some(path) => read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to read leaderboard from {}: {}", path, e)),
+        None => cached_or_fetch(id, year),
+    };
+
+    match PrivateLeaderboard::try_from(json.as_str()) {
+        Ok(leaderboard) => print!("{}", leaderboard),
+        Err(err) => println!("Failed to parse leaderboard: {}", err),
+    }
+}
+
+fn cached_or_fetch(id: u64, year: Year) -> String {
+    let name = id.to_string();
+
+    if let Ok(json) = read_leaderboard(&name, year, CACHE_MAX_AGE) {
+        println!("Using leaderboard fetched less than 15 minutes ago");
+
+        return json;
+    }
+
+    println!("Downloading...");
+    let json = fetch_private_leaderboard(year, id)
+        .unwrap_or_else(|e| panic!("Failed to fetch leaderboard: {}", e));
+
+    write_leaderboard(&name, year, &json).unwrap();
+
+    json
+}
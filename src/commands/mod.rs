@@ -1,3 +1,5 @@
 pub mod input;
+pub mod leaderboard;
 pub mod output;
 pub mod solve;
+pub mod visualize;
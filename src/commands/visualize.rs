@@ -0,0 +1,86 @@
+use crate::aoc::file_system::read_input;
+use crate::aoc::puzzle_day::PuzzleDay;
+use crate::solutions::solution;
+use crate::utils::render::frames::{Frame, FrameRecorder};
+use crate::utils::render::gif;
+use crate::utils::render::image::Image;
+use std::fs;
+use std::path::Path;
+use std::thread::sleep;
+use std::time::Duration;
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Plays frames in the terminal, or writes them to a `.gif` file or a directory of numbered PNG files
+pub fn visualize(
+    puzzle_day: PuzzleDay,
+    output: Option<String>,
+    fps: u32,
+    last: Option<usize>,
+    scale: usize,
+) {
+    let day_number = puzzle_day.day_number();
+    let year = puzzle_day.year();
+
+    let solution = solution(puzzle_day);
+
+    let input = match read_input(day_number.to_string().as_str(), year) {
+        Ok(val) => val,
+        Err(_) => panic!("Failed to read input. Download it first."),
+    };
+
+    let mut recorder = FrameRecorder::new(last);
+    if let Err(err) = solution.visualize(&input, &mut recorder) {
+        println!("{}", err);
+        return;
+    }
+
+    let frames = recorder.into_frames();
+    println!("Recorded {} frames", frames.len());
+
+    let result = match output {
+        None => {
+            play(&frames, fps);
+            Ok(())
+        }
+        Some(path) if path.ends_with(".gif") => write_gif(&frames, Path::new(&path), fps, scale),
+        Some(path) => write_images(&frames, Path::new(&path), scale),
+    };
+
+    if let Err(err) = result {
+        println!("Failed to save frames: {}", err);
+    }
+}
+
+fn play(frames: &[Frame], fps: u32) {
+    let delay = Duration::from_secs(1) / fps.max(1);
+
+    for (i, frame) in frames.iter().enumerate() {
+        print!("{}{}", CLEAR_SCREEN, frame.to_ansi());
+        println!("Frame {}/{}", i + 1, frames.len());
+        sleep(delay);
+    }
+}
+
+fn write_gif(frames: &[Frame], path: &Path, fps: u32, scale: usize) -> Result<(), String> {
+    let images: Vec<Image> = frames.iter().map(|f| f.to_image(scale)).collect();
+    let delay = (100 / fps.max(1)) as u16;
+
+    fs::write(path, gif::encode(&images, delay)).map_err(|e| e.to_string())?;
+    println!("Saved {}", path.display());
+
+    Ok(())
+}
+
+fn write_images(frames: &[Frame], dir: &Path, scale: usize) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+
+    for (i, frame) in frames.iter().enumerate() {
+        frame
+            .to_image(scale)
+            .save(&dir.join(format!("frame_{:05}.png", i + 1)))?;
+    }
+    println!("Saved frames in {}", dir.display());
+
+    Ok(())
+}
@@ -3,8 +3,10 @@ extern crate core;
 use crate::aoc::puzzle_day::PuzzleDay;
 use crate::aoc::year::Year::Year2025;
 use crate::commands::input::download_input;
+use crate::commands::leaderboard::show_leaderboard;
 use crate::commands::output::download_output;
 use crate::commands::solve::solve;
+use crate::commands::visualize::visualize;
 use aoc::day_number::DayNumber;
 use aoc::puzzle_part::PuzzlePart;
 use aoc::year::Year;
@@ -50,6 +52,34 @@ enum Command {
         #[arg(short, long, help = "Force download even if exists")]
         force: bool,
     },
+    /// Shows private leaderboard for given year
+    #[clap(short_flag = 'l')]
+    Leaderboard {
+        /// Id of the private leaderboard
+        id: u64,
+        #[arg(
+            short,
+            long,
+            help = "Read leaderboard from saved JSON file instead of AoC server"
+        )]
+        file: Option<String>,
+    },
+    /// Plays frames of the simulation in the terminal or saves them as images
+    #[clap(short_flag = 'v')]
+    Visualize {
+        #[arg(
+            short,
+            long,
+            help = "Save frames to a .gif file or as PNG files in given directory"
+        )]
+        output: Option<String>,
+        #[arg(short, long, default_value_t = 10, help = "Frames per second")]
+        fps: u32,
+        #[arg(short, long, help = "Keep only given number of last frames")]
+        last: Option<usize>,
+        #[arg(short, long, default_value_t = 4, help = "Pixels per cell in images")]
+        scale: usize,
+    },
 }
 
 fn parse_day(s: &str) -> Result<u8, String> {
@@ -82,5 +112,15 @@ fn main() {
             download_input(puzzle_day)
         }
         Command::Output { force } => download_output(day_number_option, year, force),
+        Command::Leaderboard { id, file } => show_leaderboard(id, year, file),
+        Command::Visualize {
+            output,
+            fps,
+            last,
+            scale,
+        } => {
+            println!("=== Day {} in {} ===", day_number, year);
+            visualize(puzzle_day, output, fps, last, scale)
+        }
     }
 }
@@ -1,5 +1,6 @@
 use crate::aoc::puzzle_day::PuzzleDay;
 use crate::aoc::year::Year;
+use crate::utils::render::frames::FrameSink;
 
 mod year2015;
 mod year2016;
@@ -10,6 +11,11 @@ mod year2025;
 pub trait Solution {
     fn part_one(&self, input: &str) -> String;
     fn part_two(&self, input: &str) -> String;
+
+    /// Pushes frames of the simulation for the `visualize` command
+    fn visualize(&self, _input: &str, _frames: &mut dyn FrameSink) -> Result<(), String> {
+        Err("Visualization is not supported for this day".to_string())
+    }
 }
 
 pub fn solution(puzzle_day: PuzzleDay) -> Box<dyn Solution> {
@@ -1,8 +1,9 @@
 use crate::solutions::Solution;
-use crate::utils::point::Point;
+use crate::utils::point_n::PointN;
 use std::collections::HashMap;
 
-type Houses = HashMap<Point, u64>;
+type House = PointN<i32, 2>;
+type Houses = HashMap<House, u64>;
 
 pub struct Day03;
 
@@ -19,7 +20,7 @@ impl Solution for Day03 {
 impl Day03 {
     fn solve(&self, input: &str, santas: usize) -> usize {
         let mut visited_houses: Houses = HashMap::new();
-        let mut santas = vec![Point::new(0, 0); santas];
+        let mut santas = vec![House::origin(); santas];
 
         for santa in &santas {
             visited_houses.insert(*santa, 1);
@@ -36,17 +37,17 @@ impl Day03 {
         visited_houses.len()
     }
 
-    fn make_move(&self, point: Point, b: u8) -> Point {
+    fn make_move(&self, house: House, b: u8) -> House {
         match b {
-            b'>' => point.east(),
-            b'<' => point.west(),
-            b'^' => point.north(),
-            b'v' => point.south(),
+            b'>' => house + House::unit(0),
+            b'<' => house - House::unit(0),
+            b'^' => house - House::unit(1),
+            b'v' => house + House::unit(1),
             _ => unreachable!(),
         }
     }
 
-    fn visit_house(&self, houses: &mut Houses, house: Point) {
+    fn visit_house(&self, houses: &mut Houses, house: House) {
         *houses.entry(house).or_default() += 1
     }
 }
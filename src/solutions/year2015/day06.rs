@@ -118,7 +118,7 @@ impl Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts = s.split_whitespace().collect::<Vec<_>>();
@@ -1,5 +1,8 @@
 use crate::solutions::Solution;
+use crate::utils::cellular_automaton::{GridAutomaton, LifeRule};
+use crate::utils::grid_search::Neighbourhood::WithDiagonals;
 use crate::utils::light_grid::LightGrid;
+use crate::utils::point::Point;
 
 const ON: u8 = b'#';
 const OFF: u8 = b'.';
@@ -10,73 +13,39 @@ pub struct Day18 {
 
 impl Solution for Day18 {
     fn part_one(&self, input: &str) -> String {
-        let mut grid: LightGrid<u8> = input.parse().unwrap();
+        let grid: LightGrid<u8> = input.parse().unwrap();
+        let mut automaton = GridAutomaton::new(grid).with_neighbourhood(WithDiagonals);
 
-        for _ in 0..self.steps {
-            grid = self.simulate_step(&grid, None);
-        }
+        automaton.run(self.steps, &LifeRule::CONWAY.transition(ON, OFF));
 
-        grid.count_equal(&ON).to_string()
+        automaton.grid().count_equal(&ON).to_string()
     }
 
     fn part_two(&self, input: &str) -> String {
-        let mut grid: LightGrid<u8> = input.parse().unwrap();
-        let corners = self.get_corner_positions(&grid);
+        let grid: LightGrid<u8> = input.parse().unwrap();
+        let corners = self.get_corner_positions(&grid).map(|corner| (corner, ON));
+        let mut automaton = GridAutomaton::new(grid)
+            .with_neighbourhood(WithDiagonals)
+            .with_fixed(corners);
 
-        for &(x, y) in &corners {
-            grid.set(x, y, ON);
-        }
+        automaton.run(self.steps, &LifeRule::CONWAY.transition(ON, OFF));
 
-        for _ in 0..self.steps {
-            grid = self.simulate_step(&grid, Some(&corners));
-        }
-
-        grid.count_equal(&ON).to_string()
+        automaton.grid().count_equal(&ON).to_string()
     }
 }
 
 impl Day18 {
-    fn simulate_step(
-        &self,
-        grid: &LightGrid<u8>,
-        stuck_corners: Option<&[(usize, usize)]>,
-    ) -> LightGrid<u8> {
-        grid.map(|x, y, &c| {
-            if let Some(corners) = stuck_corners {
-                if corners.iter().any(|&(cx, cy)| cx == x && cy == y) {
-                    return ON;
-                }
-            }
-
-            let adjacent_on = self.count_adjacent_lights(grid, x, y);
-
-            self.next_light_state(c, adjacent_on)
-        })
-    }
-
-    #[inline]
-    fn count_adjacent_lights(&self, grid: &LightGrid<u8>, x: usize, y: usize) -> usize {
-        grid.adjacent_with_diagonals(x, y)
-            .iter()
-            .filter(|&&(nx, ny)| grid.get(nx, ny) == Some(&ON))
-            .count()
-    }
-
-    #[inline]
-    fn next_light_state(&self, current: u8, adjacent_on: usize) -> u8 {
-        match (current, adjacent_on) {
-            (ON, 2..=3) => ON,
-            (OFF, 3) => ON,
-            _ => OFF,
-        }
-    }
-
     #[inline]
-    fn get_corner_positions(&self, grid: &LightGrid<u8>) -> [(usize, usize); 4] {
-        let max_x = grid.width() - 1;
-        let max_y = grid.height() - 1;
-
-        [(0, 0), (0, max_y), (max_x, 0), (max_x, max_y)]
+    fn get_corner_positions(&self, grid: &LightGrid<u8>) -> [Point; 4] {
+        let max_x = grid.width() as isize - 1;
+        let max_y = grid.height() as isize - 1;
+
+        [
+            Point::new(0, 0),
+            Point::new(0, max_y),
+            Point::new(max_x, 0),
+            Point::new(max_x, max_y),
+        ]
     }
 }
 
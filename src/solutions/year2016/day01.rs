@@ -1,27 +1,31 @@
 use crate::solutions::Solution;
 use crate::utils::direction::Direction;
-use crate::utils::point::Point;
+use crate::utils::point_n::PointN;
 use crate::utils::rotation::Rotation;
-use crate::utils::vector::Vector;
 use std::collections::HashSet;
 
+type Position = PointN<i32, 2>;
+
 pub struct Day01;
 
 impl Solution for Day01 {
     fn part_one(&self, input: &str) -> String {
-        let start = Point::default();
+        let start = Position::origin();
 
-        self.parse(input)
-            .fold(Vector::new(start, Direction::North), |vector, (r, d)| {
-                vector.rotate(r).forward_with_length(d)
-            })
-            .position()
-            .manhattan_distance(&start)
-            .to_string()
+        let (_, position) = self.parse(input).fold(
+            (Direction::North, start),
+            |(direction, position), (rotation, distance)| {
+                let direction = direction.rotate(rotation);
+
+                (direction, position + Self::step(direction) * distance)
+            },
+        );
+
+        position.manhattan_distance(&start).to_string()
     }
 
     fn part_two(&self, input: &str) -> String {
-        let start = Point::default();
+        let start = Position::origin();
 
         self.find_first_revisit(input, start)
             .unwrap()
@@ -31,7 +35,7 @@ impl Solution for Day01 {
 }
 
 impl Day01 {
-    fn parse<'a>(&self, input: &'a str) -> impl Iterator<Item = (Rotation, isize)> + 'a {
+    fn parse<'a>(&self, input: &'a str) -> impl Iterator<Item = (Rotation, i32)> + 'a {
         input.trim().split(',').map(|chunk| {
             let chunk = chunk.trim();
 
@@ -41,31 +45,37 @@ impl Day01 {
                 _ => unreachable!("Unknown rotation"),
             };
 
-            let distance = chunk[1..].parse::<isize>().expect("Invalid number");
+            let distance = chunk[1..].parse::<i32>().expect("Invalid number");
 
             (rotation, distance)
         })
     }
 
-    fn find_first_revisit(&self, input: &str, start: Point) -> Option<Point> {
-        let mut vector = Vector::new(start, Direction::North);
-        let mut visited = HashSet::new();
-        visited.insert(start);
+    fn find_first_revisit(&self, input: &str, start: Position) -> Option<Position> {
+        let mut direction = Direction::North;
+        let mut position = start;
+        let mut visited = HashSet::from([start]);
 
         for (rotation, distance) in self.parse(input) {
-            vector = vector.rotate(rotation);
+            direction = direction.rotate(rotation);
 
             for _ in 0..distance {
-                vector = vector.forward();
+                position += Self::step(direction);
 
-                if !visited.insert(vector.position()) {
-                    return Some(vector.position());
+                if !visited.insert(position) {
+                    return Some(position);
                 }
             }
         }
 
         None
     }
+
+    fn step(direction: Direction) -> Position {
+        let offset = direction.offset();
+
+        Position::new([offset.x as i32, offset.y as i32])
+    }
 }
 
 #[cfg(test)]
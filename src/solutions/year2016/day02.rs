@@ -33,13 +33,7 @@ impl Day02 {
 
     fn parse_line(&self, line: &str) -> Vec<Direction> {
         line.chars()
-            .map(|c| match c {
-                'U' => North,
-                'D' => South,
-                'L' => West,
-                'R' => East,
-                _ => unreachable!(),
-            })
+            .map(|c| Direction::try_from(c).unwrap())
             .collect()
     }
 }
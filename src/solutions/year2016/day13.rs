@@ -1,6 +1,7 @@
 use crate::solutions::Solution;
 use crate::utils::graphs::a_star::AStarBuilder;
 use crate::utils::point::Point;
+use crate::utils::point_n::PointN;
 use std::collections::HashSet;
 
 const MAX_STEPS: usize = 50;
@@ -58,11 +59,9 @@ impl Day13 {
     }
 
     fn is_open_space(&self, point: &Point, favorite_number: usize) -> bool {
-        if point.x < 0 || point.y < 0 {
+        let Some([x, y]) = PointN::from(*point).to_usize() else {
             return false;
-        }
-
-        let (x, y) = (*point).into();
+        };
 
         let result = x * x + 3 * x + 2 * x * y + y + y * y;
         let result = result + favorite_number;
@@ -1,5 +1,6 @@
 use crate::solutions::Solution;
 use crate::utils::range::Range;
+use crate::utils::range_set::RangeSet;
 use std::collections::HashMap;
 use std::str;
 
@@ -27,38 +28,13 @@ impl Solution for Day05 {
     fn part_two(&self, input: &str) -> String {
         let (seeds, maps) = parse_input_part_two(input);
 
-        let mut seeds_all = seeds;
-        for map in maps {
-            let mut processed_seeds: Vec<Range> = vec![];
-
-            let mut idx: i32 = 0;
-            let mut count: i32 = seeds_all.len() as i32;
-
-            let mut seeds_hash: HashMap<i32, Range> = HashMap::new();
-            for s in 0..seeds_all.len() {
-                seeds_hash.insert(s as i32, *seeds_all.get(s).unwrap());
-            }
-
-            while idx < count {
-                let (left, moved) = map.move_seeds(*seeds_hash.get(&idx).unwrap());
-                processed_seeds.push(moved);
-
-                for l in left {
-                    seeds_hash.insert(seeds_hash.len() as i32, l);
-                    count += 1;
-                }
-
-                idx += 1;
-            }
-
-            seeds_all = processed_seeds.clone();
-        }
-
-        seeds_all
-            .iter()
-            .map(|seed| seed.start())
-            .min()
+        maps.iter()
+            .fold(seeds.into_iter().collect::<RangeSet>(), |seeds, map| {
+                map.move_seeds(&seeds)
+            })
+            .bounds()
             .unwrap()
+            .start()
             .to_string()
     }
 }
@@ -135,22 +111,22 @@ impl Map {
         source
     }
 
-    fn move_seeds(&self, source: Range) -> (Vec<Range>, Range) {
-        for map in &self.maps {
-            if map.collide(source) {
-                let left = source.diff(&map.range);
-                let intersect = map.range.intersect(&source).unwrap();
-
-                let diff = map.destination - map.range.start();
-                let new_start = intersect.start() + diff;
-
-                let moved = intersect.move_start_at(new_start).unwrap();
+    fn move_seeds(&self, source: &RangeSet) -> RangeSet {
+        let mut left = source.clone();
+        let mut moved = RangeSet::new();
 
-                return (left, moved);
-            }
+        for map in &self.maps {
+            let covered = RangeSet::from(map.range);
+            let shifted = left
+                .intersection(&covered)
+                .shift(map.destination - map.range.start())
+                .unwrap();
+
+            moved = moved.union(&shifted);
+            left = left.difference(&covered);
         }
 
-        (vec![], source)
+        moved.union(&left)
     }
 }
 
@@ -173,10 +149,6 @@ impl MapRange {
         self.range.contains(source)
     }
 
-    fn collide(&self, source: Range) -> bool {
-        self.range.collide(&source)
-    }
-
     fn move_seed(&self, source: isize) -> Option<isize> {
         if self.range.contains(source) {
             let diff = source - self.range.start();
@@ -193,6 +165,7 @@ mod tests {
     use crate::solutions::year2023::read_2023_example;
     use crate::solutions::Solution;
     use crate::utils::range::Range;
+    use crate::utils::range_set::RangeSet;
     use std::vec;
 
     #[test]
@@ -280,11 +253,11 @@ mod tests {
             MapRange::new(68, 64, 13),
         ]);
 
-        let seed = Range::new(74, 87).unwrap();
+        let seeds = RangeSet::from(Range::new(74, 87).unwrap());
 
-        let (left, moved) = map.move_seeds(seed);
-
-        assert_eq!(vec![Range::new(74, 76).unwrap()], left);
-        assert_eq!(Range::new(45, 55).unwrap(), moved);
+        assert_eq!(
+            RangeSet::from_iter([Range::new(45, 55).unwrap(), Range::new(78, 80).unwrap()]),
+            map.move_seeds(&seeds)
+        );
     }
 }
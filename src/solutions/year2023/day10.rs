@@ -2,10 +2,10 @@ use crate::solutions::Solution;
 use crate::utils::direction::Direction;
 use crate::utils::grid::Grid;
 use crate::utils::point::Point;
-use crate::utils::shoelace_formula::shoelace_formula;
+use crate::utils::polygon::Polygon;
 use std::fmt;
 use std::fmt::Display;
-use std::ops::{Div, Sub};
+use std::ops::Div;
 use Direction::{East, North, South, West};
 
 pub struct Day10;
@@ -22,9 +22,7 @@ impl Solution for Day10 {
         let grid: Grid<Tile> = Grid::from(input);
         let chain: Vec<Point> = self.walk(&grid);
 
-        shoelace_formula(&chain)
-            .sub(chain.len() as isize)
-            .to_string()
+        Polygon::from_iter(chain).interior_points().to_string()
     }
 }
 
@@ -1,9 +1,10 @@
 use crate::solutions::Solution;
+use crate::utils::cell_legend::cell_legend;
 use crate::utils::grid::Grid;
+use crate::utils::input_sections::InputSections;
 use crate::utils::point::Point;
 use crate::utils::range::Range;
 use std::collections::BTreeMap;
-use std::fmt::{Display, Formatter};
 
 pub struct Day13;
 
@@ -70,7 +71,10 @@ impl Solution for Day13 {
 
 impl Day13 {
     fn parse_input(input: &str) -> Vec<Grid<Type>> {
-        input.split("\n\n").map(Grid::from).collect()
+        InputSections::from(input)
+            .iter()
+            .map(|section| section.grid().unwrap())
+            .collect()
     }
 
     fn find_mirror_with_skip(
@@ -110,7 +114,7 @@ impl Day13 {
         data.get(&(index as isize))
             .unwrap()
             .iter()
-            .map(|(_, &c)| c.clone())
+            .map(|(_, &c)| *c)
             .collect()
     }
 
@@ -143,29 +147,11 @@ impl Day13 {
     }
 }
 
-#[derive(PartialEq, Eq, Ord, PartialOrd, Clone, Debug)]
-enum Type {
-    Ash,
-    Rock,
-}
-
-impl From<char> for Type {
-    fn from(value: char) -> Self {
-        match value {
-            '.' => Type::Ash,
-            '#' => Type::Rock,
-            _ => panic!("Unknown type"),
-        }
-    }
-}
-
-impl Display for Type {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let c = match self {
-            Type::Ash => '.',
-            Type::Rock => '#',
-        };
-        write!(f, "{}", c)
+cell_legend! {
+    #[derive(Ord, PartialOrd)]
+    enum Type {
+        Ash = '.',
+        Rock = '#',
     }
 }
 
@@ -215,7 +201,7 @@ mod tests {
     fn find_mirror_test() {
         let input = read_2023_example("13");
 
-        let grids: Vec<Grid<Type>> = input.split("\n\n").map(Grid::from).collect();
+        let grids: Vec<Grid<Type>> = Day13::parse_input(&input);
         let first_grid = grids.first().unwrap();
 
         assert_eq!(None, Day13::find_mirror(first_grid.rows()));
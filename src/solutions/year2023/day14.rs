@@ -1,23 +1,33 @@
 use crate::solutions::Solution;
+use crate::utils::cell_legend::{cell_legend, CellLegend};
 use crate::utils::direction::Direction;
 use crate::utils::direction::Direction::{East, North, South, West};
 use crate::utils::grid::Grid;
 use crate::utils::point::Point;
 use crate::utils::range::Range;
+use crate::utils::render::frames::{Frame, FrameSink};
 use crate::utils::surface_range::SurfaceRange;
 use itertools::Itertools;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 
 pub struct Day14;
 
+cell_legend! {
+    enum Tile {
+        Empty = '.',
+        Rounded = 'O',
+        Cube = '#',
+    }
+}
+
 impl Solution for Day14 {
     fn part_one(&self, input: &str) -> String {
-        let grid: Grid<char> = Grid::from(input);
+        let grid: Grid<Tile> = Grid::from(input);
 
-        let rounded_rocks = Rocks::from(grid.get_all_positions(&'O'));
-        let cube_rocks = Rocks::from(grid.get_all_positions(&'#'));
+        let rounded_rocks = Rocks::from(grid.get_all_positions(&Tile::Rounded));
+        let cube_rocks = Rocks::from(grid.get_all_positions(&Tile::Cube));
 
         let surface_range = grid.surface();
 
@@ -29,9 +39,9 @@ impl Solution for Day14 {
     fn part_two(&self, input: &str) -> String {
         const NUMBER_OF_CYCLES: usize = 1_000_000_000;
 
-        let grid: Grid<char> = Grid::from(input);
-        let mut rounded_rocks = Rocks::from(grid.get_all_positions(&'O'));
-        let cube_rocks = Rocks::from(grid.get_all_positions(&'#'));
+        let grid: Grid<Tile> = Grid::from(input);
+        let mut rounded_rocks = Rocks::from(grid.get_all_positions(&Tile::Rounded));
+        let cube_rocks = Rocks::from(grid.get_all_positions(&Tile::Cube));
         let surface_range = grid.surface();
 
         let mut history: Vec<u64> = Vec::new();
@@ -62,6 +72,39 @@ impl Solution for Day14 {
 
         Self::total_load_on_north_support_beam(surface_range.rows(), rounded_rocks).to_string()
     }
+
+    /// Every tilt until the rocks end up in a position seen before
+    fn visualize(&self, input: &str, frames: &mut dyn FrameSink) -> Result<(), String> {
+        type Tilt = fn(SurfaceRange, Rocks, &Rocks) -> Rocks;
+        let tilts: [Tilt; 4] = [
+            Self::tilt_north,
+            Self::tilt_west,
+            Self::tilt_south,
+            Self::tilt_east,
+        ];
+
+        let grid: Grid<Tile> = Grid::from(input);
+        let mut rounded_rocks = Rocks::from(grid.get_all_positions(&Tile::Rounded));
+        let cube_rocks = Rocks::from(grid.get_all_positions(&Tile::Cube));
+        let surface_range = grid.surface();
+
+        let mut seen: HashSet<u64> = HashSet::new();
+        while seen.insert(rounded_rocks.hash()) {
+            for tilt in tilts {
+                rounded_rocks = tilt(surface_range, rounded_rocks, &cube_rocks);
+
+                frames.push(&|| {
+                    let mut platform = Grid::filled(surface_range, Tile::Empty);
+                    platform.modify_many(cube_rocks.all.clone(), Tile::Cube);
+                    platform.modify_many(rounded_rocks.all.clone(), Tile::Rounded);
+
+                    Frame::from_grid(&platform, Tile::to_char)
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Day14 {
@@ -244,10 +287,11 @@ impl From<Vec<Point>> for Rocks {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::year2023::day14::{Day14, Rocks};
+    use crate::solutions::year2023::day14::{Day14, Rocks, Tile};
     use crate::solutions::year2023::read_2023_example;
     use crate::solutions::Solution;
     use crate::utils::grid::Grid;
+    use crate::utils::render::frames::FrameRecorder;
 
     #[test]
     fn part_one_example_test() {
@@ -267,7 +311,7 @@ mod tests {
     fn cycle_test() {
         let input = read_2023_example("14");
 
-        let grid: Grid<char> = Grid::from(input.as_str());
+        let grid: Grid<Tile> = Grid::from(input.as_str());
 
         let after_first_cycle = cycle(grid);
         let expected = ".....#....
@@ -315,15 +359,40 @@ mod tests {
         assert_eq!(expected, after_third_cycle.to_string());
     }
 
-    fn cycle(grid: Grid<char>) -> Grid<char> {
-        let rounded_rocks = Rocks::from(grid.get_all_positions(&'O'));
-        let cube_rocks = Rocks::from(grid.get_all_positions(&'#'));
+    #[test]
+    fn visualize_test() {
+        let input = read_2023_example("14");
+        let mut recorder = FrameRecorder::new(None);
+
+        Day14.visualize(input.as_str(), &mut recorder).unwrap();
+        let frames = recorder.into_frames();
+
+        assert_eq!(0, frames.len() % 4);
+        assert_eq!(
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+",
+            frames[3].grid().to_string()
+        );
+    }
+
+    fn cycle(grid: Grid<Tile>) -> Grid<Tile> {
+        let rounded_rocks = Rocks::from(grid.get_all_positions(&Tile::Rounded));
+        let cube_rocks = Rocks::from(grid.get_all_positions(&Tile::Cube));
 
         let after_first_cycle = Day14::cycle(grid.surface(), rounded_rocks, &cube_rocks);
 
-        let mut grid: Grid<char> = Grid::filled(grid.surface(), '.');
-        grid.modify_many(after_first_cycle.all, 'O');
-        grid.modify_many(cube_rocks.all, '#');
+        let mut grid: Grid<Tile> = Grid::filled(grid.surface(), Tile::Empty);
+        grid.modify_many(after_first_cycle.all, Tile::Rounded);
+        grid.modify_many(cube_rocks.all, Tile::Cube);
 
         grid
     }
@@ -1,10 +1,9 @@
 use crate::solutions::Solution;
+use crate::utils::beam_tracer::{BeamAction, BeamTracer};
 use crate::utils::direction::Direction;
 use crate::utils::grid::Grid;
 use crate::utils::point::Point;
 use crate::utils::vector::Vector;
-use itertools::Itertools;
-use std::collections::VecDeque;
 use Direction::{East, North, South, West};
 
 pub struct Day16;
@@ -12,17 +11,19 @@ pub struct Day16;
 impl Solution for Day16 {
     fn part_one(&self, input: &str) -> String {
         let grid: Grid<Tile> = Grid::from(input);
+        let tracer = BeamTracer::new(&grid, |tile| *tile != Tile::Empty);
 
-        Self::energize(Vector::new(Point::new(0, 0), East), &grid).to_string()
+        Self::energize(Vector::new(Point::new(0, 0), East), &tracer).to_string()
     }
 
     fn part_two(&self, input: &str) -> String {
         let grid: Grid<Tile> = Grid::from(input);
+        let tracer = BeamTracer::new(&grid, |tile| *tile != Tile::Empty);
         let starting_points: Vec<Vector> = grid.surface().vectors_pointing_inwards();
 
         starting_points
             .into_iter()
-            .map(|start| Self::energize(start, &grid))
+            .map(|start| Self::energize(start, &tracer))
             .max()
             .unwrap()
             .to_string()
@@ -30,57 +31,26 @@ impl Solution for Day16 {
 }
 
 impl Day16 {
-    fn energize(start: Vector, grid: &Grid<Tile>) -> usize {
-        let surface_range = grid.surface();
-
-        let mut beams: VecDeque<Vector> = VecDeque::new();
-        beams.push_back(start);
-
-        let mut history: Vec<Vector> = Vec::new();
-
-        while let Some(mut beam) = beams.pop_front() {
-            while surface_range.contains(beam.position()) && !history.contains(&beam) {
-                let position = beam.position();
-
-                history.push(beam);
-
-                let tile = grid.get_for_point(&position).unwrap();
-                let facing = beam.facing();
-
-                beam = match tile {
-                    Tile::SplitterVer if [East, West].contains(&facing) => {
-                        let new = beam.rotate_ccw().forward();
-                        beams.push_back(new);
+    fn energize(start: Vector, tracer: &BeamTracer<Grid<Tile>>) -> usize {
+        tracer.trace(start, Self::bounce).energised_count()
+    }
 
-                        beam.rotate_cw().forward()
-                    }
-                    Tile::SplitterHor if [South, North].contains(&facing) => {
-                        let new = beam.rotate_ccw().forward();
-                        beams.push_back(new);
+    fn bounce(beam: &Vector, tile: &Tile) -> BeamAction {
+        let facing = beam.facing();
 
-                        beam.rotate_cw().forward()
-                    }
-                    Tile::MirrorFWD if [South, North].contains(&facing) => {
-                        beam.rotate_cw().forward()
-                    }
-                    Tile::MirrorFWD if [East, West].contains(&facing) => {
-                        beam.rotate_ccw().forward()
-                    }
-                    Tile::MirrorBWD if [South, North].contains(&facing) => {
-                        beam.rotate_ccw().forward()
-                    }
-                    Tile::MirrorBWD if [East, West].contains(&facing) => beam.rotate_cw().forward(),
-                    _ => beam.forward(),
-                };
+        match tile {
+            Tile::SplitterVer if [East, West].contains(&facing) => {
+                BeamAction::Split(facing.ccw(), facing.cw())
             }
+            Tile::SplitterHor if [South, North].contains(&facing) => {
+                BeamAction::Split(facing.ccw(), facing.cw())
+            }
+            Tile::MirrorFWD if [South, North].contains(&facing) => BeamAction::Turn(facing.cw()),
+            Tile::MirrorFWD if [East, West].contains(&facing) => BeamAction::Turn(facing.ccw()),
+            Tile::MirrorBWD if [South, North].contains(&facing) => BeamAction::Turn(facing.ccw()),
+            Tile::MirrorBWD if [East, West].contains(&facing) => BeamAction::Turn(facing.cw()),
+            _ => BeamAction::Pass,
         }
-
-        history
-            .iter()
-            .map(|b| b.position())
-            .unique()
-            .collect::<Vec<Point>>()
-            .len()
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::solutions::Solution;
 use crate::utils::direction::Direction;
 use crate::utils::point::Point;
-use crate::utils::shoelace_formula::shoelace_formula;
+use crate::utils::polygon::Polygon;
 use itertools::Itertools;
 
 pub struct Day18;
@@ -64,7 +64,7 @@ impl Day18 {
 
     fn solve(instructions: Vec<Instruction>) -> String {
         let mut last = Point::new(0, 0);
-        let mut trenches: Vec<Point> = vec![last];
+        let mut trenches: Vec<Point> = Vec::new();
 
         for instruction in instructions {
             let new = last.move_in_with_length(instruction.direction, instruction.length as isize);
@@ -73,7 +73,7 @@ impl Day18 {
             last = new;
         }
 
-        shoelace_formula(&trenches).to_string()
+        Polygon::from_iter(trenches).lattice_points().to_string()
     }
 }
 
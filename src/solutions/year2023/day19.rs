@@ -1,6 +1,8 @@
 use crate::solutions::year2023::day19::Action::MoveToWorkflow;
 use crate::solutions::year2023::day19::Rule::{Actionable, Conditional};
 use crate::solutions::Solution;
+use crate::utils::hyper_box::HyperBox;
+use crate::utils::input_sections::InputSections;
 use crate::utils::range::Range;
 use itertools::Itertools;
 use std::collections::HashMap;
@@ -45,10 +47,12 @@ impl Solution for Day19 {
 
 impl Day19 {
     fn parse_workflows(input: &str) -> Workflows {
-        let (workflows_string, _) = input.split("\n\n").collect_tuple().unwrap();
+        let sections = InputSections::from(input);
+        let (workflows, _) = sections.pair().unwrap();
 
-        workflows_string
+        workflows
             .lines()
+            .iter()
             .map(|line| {
                 let (name, rest) = line.split_terminator('{').collect_tuple().unwrap();
 
@@ -58,10 +62,12 @@ impl Day19 {
     }
 
     fn parse_parts(input: &str) -> Vec<Part> {
-        let (_, parts_string) = input.split("\n\n").collect_tuple().unwrap();
+        let sections = InputSections::from(input);
+        let (_, parts) = sections.pair().unwrap();
 
-        parts_string
+        parts
             .lines()
+            .iter()
             .map(|line| {
                 let trimmed = &line[1..line.len() - 1];
                 let numbers: Vec<isize> = trimmed
@@ -79,77 +85,50 @@ impl Day19 {
     }
 
     fn combinations(workflows: &Workflows, range_to: usize) -> usize {
-        let part_ranges = PartRanges::new(range_to);
+        let parts = PartRanges::cube(Range::new(1, range_to as isize).unwrap());
 
-        Self::combinations_for(workflows, "in", part_ranges, range_to)
+        Self::combinations_for(workflows, "in", parts) as usize
     }
 
-    fn combinations_for(
-        workflows: &Workflows,
-        name: &str,
-        part_ranges: PartRanges,
-        range_to: usize,
-    ) -> usize {
+    fn combinations_for(workflows: &Workflows, name: &str, parts: PartRanges) -> u128 {
         let workflow = workflows.get(name).unwrap();
 
-        let mut combinations: usize = 0;
-        let iter: Iter<Rule> = workflow.rules.iter();
-        let mut part_ranges = part_ranges;
+        let mut combinations = 0;
+        let mut remaining = Some(parts);
 
-        for rule in iter {
-            match rule {
+        for rule in &workflow.rules {
+            let Some(parts) = remaining else {
+                break;
+            };
+
+            let (matching, rest) = match rule {
                 Conditional(condition) => {
-                    let Condition {
-                        operation,
-                        value,
-                        category,
-                        action,
-                    } = condition;
-                    let true_range = match operation {
-                        '>' => Range::new(*value + 1, range_to as isize).unwrap(),
-                        '<' => Range::new(1, *value - 1).unwrap(),
-                        _ => unreachable!(),
-                    };
-
-                    if let Some(true_ranges) = part_ranges.intersect(*category, &true_range) {
-                        combinations += match action {
-                            Accepted => true_ranges.combinations(),
-                            Rejected => 0,
-                            MoveToWorkflow(workflow) => Self::combinations_for(
-                                workflows,
-                                workflow.as_str(),
-                                true_ranges,
-                                range_to,
-                            ),
-                        };
-                    }
+                    let axis = Part::axis(condition.category);
 
-                    let diff = Range::new(1, range_to as isize).unwrap().diff(&true_range);
-                    let false_range = diff.first().unwrap();
+                    match condition.operation {
+                        '<' => parts.split(axis, condition.value),
+                        '>' => {
+                            let (rest, matching) = parts.split(axis, condition.value + 1);
 
-                    let false_ranges = part_ranges.intersect(*category, false_range);
-                    if false_ranges.is_none() {
-                        break;
+                            (matching, rest)
+                        }
+                        _ => unreachable!(),
                     }
-
-                    part_ranges = part_ranges.intersect(*category, false_range).unwrap();
                 }
-                Actionable(action) => match action {
-                    Accepted => combinations += part_ranges.combinations(),
-                    Rejected => {
-                        break;
-                    }
+                Actionable(_) => (Some(parts), None),
+            };
+
+            if let Some(matching) = matching {
+                combinations += match rule.action() {
+                    Accepted => matching.volume(),
+                    Rejected => 0,
                     MoveToWorkflow(workflow) => {
-                        combinations += Self::combinations_for(
-                            workflows,
-                            workflow.as_str(),
-                            part_ranges,
-                            range_to,
-                        );
-                        break;
+                        Self::combinations_for(workflows, workflow.as_str(), matching)
                     }
-                },
+                };
             }
+
+            remaining = rest;
         }
 
         combinations
@@ -181,76 +160,15 @@ impl Part {
     fn sum(&self) -> isize {
         self.x + self.m + self.a + self.s
     }
-}
-
-#[derive(Clone)]
-struct PartRanges {
-    x: Range,
-    m: Range,
-    a: Range,
-    s: Range,
-}
 
-impl PartRanges {
-    fn new(to: usize) -> Self {
-        Self {
-            x: Range::new(1, to as isize).unwrap(),
-            m: Range::new(1, to as isize).unwrap(),
-            a: Range::new(1, to as isize).unwrap(),
-            s: Range::new(1, to as isize).unwrap(),
-        }
-    }
-
-    fn combinations(&self) -> usize {
-        self.x.len() as usize
-            * self.m.len() as usize
-            * self.a.len() as usize
-            * self.s.len() as usize
-    }
-
-    fn intersect(&self, category: char, range: &Range) -> Option<Self> {
-        let is_allowed_to_sub = match category {
-            'x' => self.x.collide(range),
-            'm' => self.m.collide(range),
-            'a' => self.a.collide(range),
-            's' => self.s.collide(range),
-            _ => unreachable!(),
-        };
-
-        if !is_allowed_to_sub {
-            return None;
-        }
-
-        Some(match category {
-            'x' => Self {
-                x: self.x.intersect(range).unwrap(),
-                m: self.m,
-                a: self.a,
-                s: self.s,
-            },
-            'm' => Self {
-                m: self.m.intersect(range).unwrap(),
-                x: self.x,
-                a: self.a,
-                s: self.s,
-            },
-            'a' => Self {
-                a: self.a.intersect(range).unwrap(),
-                m: self.m,
-                x: self.x,
-                s: self.s,
-            },
-            's' => Self {
-                s: self.s.intersect(range).unwrap(),
-                m: self.m,
-                a: self.a,
-                x: self.x,
-            },
-            _ => unreachable!(),
-        })
+    fn axis(category: char) -> usize {
+        "xmas".find(category).unwrap()
     }
 }
 
+/// Ranges of x, m, a and s ratings, in that order
+type PartRanges = HyperBox<4>;
+
 #[derive(Debug)]
 struct Workflow {
     rules: Vec<Rule>,
@@ -332,6 +250,15 @@ enum Rule {
     Actionable(Action),
 }
 
+impl Rule {
+    fn action(&self) -> &Action {
+        match self {
+            Conditional(condition) => &condition.action,
+            Actionable(action) => action,
+        }
+    }
+}
+
 impl From<&str> for Rule {
     fn from(value: &str) -> Self {
         if value.contains(':') {
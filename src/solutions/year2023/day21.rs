@@ -1,7 +1,9 @@
 use crate::solutions::Solution;
 use crate::utils::grid::Grid;
+use crate::utils::grid_like::GridView;
+use crate::utils::grid_search::{DistanceMap, GridSearch};
 use crate::utils::point::Point;
-use std::collections::HashSet;
+use crate::utils::wrapping_grid::InfiniteGrid;
 
 pub struct Day21;
 
@@ -10,35 +12,56 @@ impl Solution for Day21 {
         Self::steps(input, 64)
     }
 
-    fn part_two(&self, _input: &str) -> String {
-        String::from('0')
+    fn part_two(&self, input: &str) -> String {
+        const STEPS: usize = 26_501_365;
+
+        let grid: Grid<char> = Grid::from(input);
+        let start = grid.get_first_position(&'S').unwrap();
+        let size = grid.rows_range().len() as usize;
+        let remainder = STEPS % size;
+
+        // the start is in the middle of an empty row and column, so every time the walk
+        // reaches a next copy of the garden, the number of reached plots grows quadratically
+        let distances = Self::distances(&InfiniteGrid::new(&grid), start, remainder + 2 * size);
+        let counts: Vec<usize> = (0..3)
+            .map(|i| distances.count_reachable_in_exactly(remainder + i * size))
+            .collect();
+
+        let n = STEPS / size;
+        let first_diff = counts[1] - counts[0];
+        let second_diff = counts[2] + counts[0] - 2 * counts[1];
+
+        (counts[0] + n * first_diff + n * (n - 1) / 2 * second_diff).to_string()
     }
 }
 
 impl Day21 {
     fn steps(input: &str, count: usize) -> String {
         let grid: Grid<char> = Grid::from(input);
-
         let start = grid.get_first_position(&'S').unwrap();
-        let surface = grid.surface();
-        let rocks = grid.get_all_positions(&'#');
 
-        let mut reached: HashSet<Point> = HashSet::from([start]);
+        let distances = Self::distances(&grid, start, count);
+
+        distances.count_reachable_in_exactly(count).to_string()
+    }
+
+    #[cfg(test)]
+    fn steps_on_infinite_grid(input: &str, count: usize) -> String {
+        let grid: Grid<char> = Grid::from(input);
+        let start = grid.get_first_position(&'S').unwrap();
 
-        for _ in 1..=count {
-            let mut new_reached: HashSet<Point> = HashSet::new();
-            for point in &reached {
-                for adj in point.adjacent() {
-                    if surface.contains(adj) && !rocks.contains(&adj) {
-                        new_reached.insert(adj);
-                    }
-                }
-            }
+        let distances = Self::distances(&InfiniteGrid::new(&grid), start, count);
 
-            reached = new_reached;
-        }
+        distances.count_reachable_in_exactly(count).to_string()
+    }
 
-        reached.len().to_string()
+    fn distances<G>(grid: &G, start: Point, max: usize) -> DistanceMap
+    where
+        G: GridView<Cell = char>,
+    {
+        GridSearch::passable(grid, |_, &c| c != '#')
+            .with_max_distance(max)
+            .distances([start])
     }
 }
 
@@ -53,4 +76,14 @@ mod tests {
 
         assert_eq!("16", Day21::steps(input.as_str(), 6));
     }
+
+    #[test]
+    fn infinite_grid_example_test() {
+        let input = read_2023_example("21");
+
+        assert_eq!("16", Day21::steps_on_infinite_grid(input.as_str(), 6));
+        assert_eq!("50", Day21::steps_on_infinite_grid(input.as_str(), 10));
+        assert_eq!("1594", Day21::steps_on_infinite_grid(input.as_str(), 50));
+        assert_eq!("6536", Day21::steps_on_infinite_grid(input.as_str(), 100));
+    }
 }
@@ -1,5 +1,10 @@
 use crate::solutions::Solution;
+use crate::utils::point::Point;
 use crate::utils::point3d::Point3D;
+use crate::utils::render::frames::{Frame, FrameSink, NoFrames};
+use crate::utils::render::Colour;
+use crate::utils::surface_range::SurfaceRange;
+use crate::utils::voxel_grid::{VoxelGrid, VoxelLike};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Display, Formatter};
@@ -42,37 +47,60 @@ impl Solution for Day22 {
             .sum::<isize>()
             .to_string()
     }
+
+    fn visualize(&self, input: &str, frames: &mut dyn FrameSink) -> Result<(), String> {
+        let bricks: Vec<Brick> = input.lines().map(Brick::from).collect();
+        Self::settle_down(bricks, frames);
+
+        Ok(())
+    }
 }
 
 impl Day22 {
     fn parse_input(input: &str) -> Bricks {
         let bricks: Vec<Brick> = input.lines().map(Brick::from).collect();
 
-        Self::settle_down(bricks)
+        Self::settle_down(bricks, &mut NoFrames)
+    }
+
+    /// Side view from the south, x grows to the right and z upwards
+    fn side_view(bricks: &[Brick], falling: &Brick, surface: SurfaceRange) -> Frame {
+        let to_2d = |p: &Point3D| Point::new(p.x, surface.y().end() + 1 - p.z);
+        let settled: HashSet<Point> = bricks
+            .iter()
+            .flat_map(|b| b.points.iter().map(to_2d))
+            .collect();
+
+        Frame::from_points(&settled, surface)
+            .highlight(falling.points.iter().map(to_2d), Colour::YELLOW)
     }
 
-    fn settle_down(bricks: Vec<Brick>) -> Bricks {
+    fn settle_down(bricks: Vec<Brick>, frames: &mut dyn FrameSink) -> Bricks {
+        let max_x = bricks
+            .iter()
+            .flat_map(|b| b.points.iter().map(|p| p.x))
+            .max()
+            .unwrap_or(0);
+        let max_z = bricks.iter().map(|b| b.highest_z()).max().unwrap_or(0);
+        let surface = SurfaceRange::from_points(0, max_x, 0, max_z - 1);
+
         let mut settled_down: Vec<Brick> = Vec::with_capacity(bricks.len());
+        let mut voxels: VoxelGrid<()> = VoxelGrid::new();
 
         let bricks_from_down = bricks
             .iter()
             .sorted_by(|a, b| a.lowest_z().cmp(&b.lowest_z()));
 
+        // one frame per brick, at the place where it lands
         for brick in bricks_from_down {
             let mut brick = brick.clone();
-            let bricks_below = settled_down.iter().rev().take(50).collect::<Vec<&Brick>>();
-
-            loop {
-                let brick_below = brick.down();
-                if bricks_below.iter().any(|b| b.collide(&brick_below))
-                    || brick_below.lowest_z() == 0
-                {
-                    settled_down.push(brick.clone());
-                    break;
-                }
-
-                brick = brick_below;
+            for _ in 0..voxels.drop_distance(&brick.points, 1) {
+                brick = brick.down();
             }
+            frames.push(&|| Self::side_view(&settled_down, &brick, surface));
+
+            voxels.extend(brick.points.iter().map(|p| (*p, ())));
+            settled_down.push(brick);
         }
 
         Bricks::new(settled_down)
@@ -275,6 +303,7 @@ mod tests {
     use crate::solutions::year2023::day22::{Brick, Bricks, Day22};
     use crate::solutions::year2023::read_2023_example;
     use crate::solutions::Solution;
+    use crate::utils::render::frames::FrameRecorder;
 
     #[test]
     fn part_one_example_test() {
@@ -290,6 +319,23 @@ mod tests {
         assert_eq!("7", Day22.part_two(input.as_str()));
     }
 
+    #[test]
+    fn visualize_test() {
+        let input = read_2023_example("22");
+        let mut recorder = FrameRecorder::new(None);
+
+        Day22.visualize(input.as_str(), &mut recorder).unwrap();
+        let mut frames = recorder.into_frames();
+        assert_eq!(7, frames.len());
+        let last = frames.pop().unwrap();
+
+        // the last brick is drawn where it lands, but not as settled yet
+        assert_eq!(
+            "...\n...\n...\n...\n...\n###\n#.#\n###\n.#.\n",
+            last.grid().to_string()
+        );
+    }
+
     #[test]
     fn brick_len_test() {
         assert_eq!(1, Brick::from("2,2,2~2,2,2").len());
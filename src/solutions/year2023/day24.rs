@@ -1,9 +1,8 @@
 use crate::solutions::Solution;
-use crate::utils::line::Line;
 use crate::utils::pair_generator::pairs;
-use crate::utils::point::Point;
+use crate::utils::parametric_line::{LineRelation, ParametricLine};
 use crate::utils::point3d::Point3D;
-use crate::utils::surface_range::SurfaceRange;
+use crate::utils::rational::Rational;
 use itertools::Itertools;
 
 pub struct Day24;
@@ -15,25 +14,21 @@ impl Solution for Day24 {
 
     fn part_two(&self, input: &str) -> String {
         let hails = Self::parse(input);
+        let rock = ParametricLine::common_line(&hails).unwrap();
+        let position = rock.position();
 
-        println!("{} {}", hails.first().unwrap().in_time(5), 5);
-        println!("{} {}", hails.get(1).unwrap().in_time(3), 3);
-        // for hail in hails {
-        //     println!("{:?}", hail);
-        // }
-
-        String::from('0')
+        (position.x + position.y + position.z).to_string()
     }
 }
 
 impl Day24 {
-    fn parse(input: &str) -> Vec<Hail> {
+    fn parse(input: &str) -> Vec<ParametricLine> {
         input
             .lines()
             .map(|line| {
                 let (pos, vel) = line.split_terminator(" @ ").collect_tuple().unwrap();
 
-                Hail::new(Self::parse_point(pos), Self::parse_point(vel))
+                ParametricLine::new(Self::parse_point(pos), Self::parse_point(vel))
             })
             .collect()
     }
@@ -43,73 +38,26 @@ impl Day24 {
     }
 
     fn solve_part_one(input: &str, from: isize, to: isize) -> String {
-        let hails: Vec<Hail2D> = Self::parse(input).into_iter().map(Hail2D::from).collect();
-        let pairs = pairs(hails);
-
-        let surface = SurfaceRange::from_points(from, to, from, to);
+        let hails = Self::parse(input);
+        let (from, to) = (Rational::from(from), Rational::from(to));
+        let in_area = |value: Rational| from <= value && value <= to;
 
-        pairs
+        pairs(hails)
             .iter()
-            .filter(|(a, b)| {
-                a.line().intersect(&b.line()).is_some_and(|result| {
-                    surface.contains(result) && !a.is_past(result) && !b.is_past(result)
-                })
+            .filter(|(a, b)| match a.relation_2d(b) {
+                LineRelation::Crossing(crossing) => {
+                    !crossing.time.is_negative()
+                        && !crossing.other_time.is_negative()
+                        && in_area(crossing.point[0])
+                        && in_area(crossing.point[1])
+                }
+                _ => false,
             })
-            .collect::<Vec<_>>()
-            .len()
+            .count()
             .to_string()
     }
 }
 
-#[derive(Debug, Clone)]
-struct Hail {
-    position: Point3D,
-    velocity: Point3D,
-}
-
-impl Hail {
-    fn new(position: Point3D, velocity: Point3D) -> Self {
-        Self { position, velocity }
-    }
-
-    fn in_time(&self, time: isize) -> Point3D {
-        self.position + self.velocity * time
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-struct Hail2D {
-    position: Point,
-    velocity: Point,
-}
-
-impl Hail2D {
-    fn new(position: Point, velocity: Point) -> Self {
-        Self { position, velocity }
-    }
-
-    fn line(&self) -> Line {
-        Line::new(self.position, self.position + self.velocity)
-    }
-
-    fn is_past(&self, point: Point) -> bool {
-        let next = self.position + self.velocity;
-        let to_start = self.position.manhattan_distance(&point);
-        let to_next = next.manhattan_distance(&point);
-
-        to_next > to_start
-    }
-}
-
-impl From<Hail> for Hail2D {
-    fn from(value: Hail) -> Self {
-        let pos = value.position;
-        let vel = value.velocity;
-
-        Self::new(Point::new(pos.x, pos.y), Point::new(vel.x, vel.y))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::solutions::year2023::day24::Day24;
@@ -123,7 +71,6 @@ mod tests {
         assert_eq!("2", Day24::solve_part_one(input.as_str(), 7, 27));
     }
 
-    #[ignore]
     #[test]
     fn part_two_example_test() {
         let input = read_2023_example("24");
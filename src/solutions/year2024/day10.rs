@@ -1,58 +1,47 @@
 use crate::solutions::Solution;
 use crate::utils::grid::Grid;
+use crate::utils::grid_search::{DistanceMap, GridSearch};
 use crate::utils::point::Point;
-use itertools::Itertools;
 
 pub struct Day10;
 
 impl Solution for Day10 {
     fn part_one(&self, input: &str) -> String {
-        self.solve(input, |points| points.into_iter().unique().collect())
+        self.solve(input, |_, _| 1)
     }
 
     fn part_two(&self, input: &str) -> String {
-        self.solve(input, |points| points)
+        self.solve(input, |trails, point| trails.path_count(point))
     }
 }
 
 impl Day10 {
-    fn solve<F>(&self, input: &str, modify_points: F) -> String
+    fn solve<F>(&self, input: &str, score: F) -> String
     where
-        F: Fn(Vec<Point>) -> Vec<Point>,
+        F: Fn(&DistanceMap, &Point) -> usize,
     {
         let grid = Grid::from_custom(input.trim(), |c| {
             c.to_digit(10).map(|x| x as i16).unwrap_or(-1)
         });
+        let search = GridSearch::new(&grid, |from, to| {
+            grid.get_for_point(to).copied() == grid.get_for_point(from).map(|height| height + 1)
+        });
+
+        let ends = grid.get_all_positions(&9);
 
         grid.get_all_positions(&0)
-            .iter()
-            .map(|&start| self.process_path(&grid, start, &modify_points))
+            .into_iter()
+            .map(|start| {
+                let trails = search.distances([start]);
+
+                ends.iter()
+                    .filter(|end| trails.distance(end).is_some())
+                    .map(|end| score(&trails, end))
+                    .sum::<usize>()
+            })
             .sum::<usize>()
             .to_string()
     }
-
-    fn process_path<F>(&self, grid: &Grid<i16>, start: Point, modify_points: &F) -> usize
-    where
-        F: Fn(Vec<Point>) -> Vec<Point>,
-    {
-        let mut current_points = vec![start];
-        for current in 0..9 {
-            let next = current + 1;
-            current_points = modify_points(
-                current_points
-                    .iter()
-                    .flat_map(|point| {
-                        point.adjacent().into_iter().filter(|next_point| {
-                            grid.get_for_point(next_point)
-                                .is_some_and(|&value| value == next)
-                        })
-                    })
-                    .collect(),
-            );
-        }
-
-        current_points.len()
-    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use crate::solutions::Solution;
 use crate::utils::grid::Grid;
+use crate::utils::grid_like::GridLike;
 
 pub struct Day12;
 
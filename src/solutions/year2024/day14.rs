@@ -1,6 +1,7 @@
 use crate::solutions::Solution;
 use crate::utils::moving_point::MovingPoint;
 use crate::utils::point::Point;
+use crate::utils::render::frames::{Frame, FrameSink, NoFrames};
 use crate::utils::surface_range::SurfaceRange;
 use itertools::Itertools;
 use std::collections::HashSet;
@@ -39,6 +40,18 @@ impl Solution for Day14 {
     }
 
     fn part_two(&self, input: &str) -> String {
+        self.find_tree(input, &mut NoFrames).to_string()
+    }
+
+    fn visualize(&self, input: &str, frames: &mut dyn FrameSink) -> Result<(), String> {
+        self.find_tree(input, frames);
+
+        Ok(())
+    }
+}
+
+impl Day14 {
+    fn find_tree(&self, input: &str, frames: &mut dyn FrameSink) -> usize {
         let mut robots = self.parse(input);
 
         let mut second = 0;
@@ -46,18 +59,16 @@ impl Solution for Day14 {
             second += 1;
             robots = self.move_all(robots, 1);
 
-            let points = robots.iter().map(|robot| robot.position()).collect_vec();
-            let points: HashSet<Point> = HashSet::from_iter(points);
+            let points: HashSet<Point> = robots.iter().map(|robot| robot.position()).collect();
+            frames.push(&|| Frame::from_points(&points, self.surface));
 
             // when every robot is on unique position
             if points.len() == robots.len() {
-                return second.to_string();
+                return second;
             }
         }
     }
-}
 
-impl Day14 {
     fn parse(&self, input: &str) -> Vec<MovingPoint> {
         input
             .lines()
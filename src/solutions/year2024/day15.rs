@@ -1,17 +1,28 @@
 use crate::solutions::Solution;
+use crate::utils::cell_legend::{cell_legend, CellLegend};
 use crate::utils::direction::Direction;
 use crate::utils::grid::Grid;
+use crate::utils::input_sections::InputSections;
 use crate::utils::point::Point;
 use crate::utils::range::Range;
+use crate::utils::render::frames::{Frame, FrameSink, NoFrames};
+use crate::utils::render::Colour;
 use crate::utils::surface_range::SurfaceRange;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
 pub struct Day15;
 
-const BOX: char = 'O';
-const OBSTACLE: char = '#';
-const ROBOT: char = '@';
+cell_legend! {
+    enum Tile {
+        Empty = '.',
+        Obstacle = '#',
+        Box = 'O',
+        BoxLeft = '[',
+        BoxRight = ']',
+        Robot = '@',
+    }
+}
 
 impl Solution for Day15 {
     fn part_one(&self, input: &str) -> String {
@@ -21,11 +32,22 @@ impl Solution for Day15 {
     fn part_two(&self, input: &str) -> String {
         self.solve(input, 2)
     }
+
+    fn visualize(&self, input: &str, frames: &mut dyn FrameSink) -> Result<(), String> {
+        self.simulate(input, 2, frames);
+
+        Ok(())
+    }
 }
 
 impl Day15 {
     fn solve(&self, input: &str, scale: isize) -> String {
+        self.simulate(input, scale, &mut NoFrames)
+    }
+
+    fn simulate(&self, input: &str, scale: isize, frames: &mut dyn FrameSink) -> String {
         let (grid, directions) = self.parse(input);
+        let surface = grid.surface();
 
         let obstacles = self.create_scaled_obstacles(&grid, scale);
         let mut boxes = self.create_scaled_boxes(&grid, scale);
@@ -35,6 +57,13 @@ impl Day15 {
             if Self::can_move(&robot, direction, &boxes, &obstacles) {
                 robot = Self::move_(&robot, direction, &mut boxes);
             }
+
+            frames.push(&|| {
+                let warehouse = self.warehouse(&surface, scale, &obstacles, &boxes, &robot);
+
+                Frame::from_grid(&warehouse, Tile::to_char)
+                    .highlight(robot.points.clone(), Colour::RED)
+            });
         }
 
         boxes
@@ -45,15 +74,15 @@ impl Day15 {
             .to_string()
     }
 
-    fn create_scaled_obstacles(&self, grid: &Grid<char>, scale: isize) -> HashSet<Point> {
-        grid.get_all_positions(&OBSTACLE)
+    fn create_scaled_obstacles(&self, grid: &Grid<Tile>, scale: isize) -> HashSet<Point> {
+        grid.get_all_positions(&Tile::Obstacle)
             .iter()
             .flat_map(|p| self.points_in_scale(p, scale, scale))
             .collect()
     }
 
-    fn create_scaled_boxes(&self, grid: &Grid<char>, scale: isize) -> HashMap<Point, Movable> {
-        grid.get_all_positions(&BOX)
+    fn create_scaled_boxes(&self, grid: &Grid<Tile>, scale: isize) -> HashMap<Point, Movable> {
+        grid.get_all_positions(&Tile::Box)
             .iter()
             .flat_map(|p| {
                 let offsets = self.points_in_scale(p, scale, scale);
@@ -67,8 +96,8 @@ impl Day15 {
             .collect()
     }
 
-    fn create_scaled_robot(&self, grid: &Grid<char>, scale: isize) -> Movable {
-        grid.get_first_position(&ROBOT)
+    fn create_scaled_robot(&self, grid: &Grid<Tile>, scale: isize) -> Movable {
+        grid.get_first_position(&Tile::Robot)
             .map(|p| {
                 let offsets = self.points_in_scale(&p, scale, 1);
 
@@ -85,27 +114,18 @@ impl Day15 {
             .collect()
     }
 
-    fn parse(&self, input: &str) -> (Grid<char>, Vec<Direction>) {
-        input
-            .split_once("\n\n")
-            .map(|(grid, directions)| {
-                (
-                    Grid::<char>::from(grid),
-                    directions
-                        .lines()
-                        .flat_map(|s| {
-                            s.chars().map(|c| match c {
-                                '^' => Direction::North,
-                                '>' => Direction::East,
-                                '<' => Direction::West,
-                                'v' => Direction::South,
-                                _ => unreachable!("bad direction: {}", c),
-                            })
-                        })
-                        .collect(),
-                )
-            })
-            .unwrap()
+    fn parse(&self, input: &str) -> (Grid<Tile>, Vec<Direction>) {
+        let sections = InputSections::from(input);
+        let (grid, directions) = sections.pair().unwrap();
+
+        (
+            grid.grid().unwrap(),
+            directions
+                .lines()
+                .iter()
+                .flat_map(|s| s.chars().map(|c| Direction::try_from(c).unwrap()))
+                .collect(),
+        )
     }
 
     fn can_move(
@@ -167,32 +187,41 @@ impl Day15 {
         next
     }
 
-    fn _print_grid(
+    fn warehouse(
         &self,
         grid_surface: &SurfaceRange,
+        scale: isize,
         obstacles: &HashSet<Point>,
         boxes: &HashMap<Point, Movable>,
         robot: &Movable,
-        dir: Option<Direction>,
-    ) {
+    ) -> Grid<Tile> {
         let grid_surface = SurfaceRange::new(
-            Range::new(grid_surface.x().start(), grid_surface.x().end() * 2 + 1).unwrap(),
+            Range::new(
+                grid_surface.x().start(),
+                (grid_surface.x().end() + 1) * scale - 1,
+            )
+            .unwrap(),
             grid_surface.y(),
         );
-        let mut grid_print: Grid<char> = Grid::filled(grid_surface, '.');
-        grid_print.modify_many(obstacles.clone().into_iter().collect_vec(), OBSTACLE);
+        let mut grid_print: Grid<Tile> = Grid::filled(grid_surface, Tile::Empty);
+        grid_print.modify_many(obstacles.clone().into_iter().collect_vec(), Tile::Obstacle);
 
         let boxes: HashSet<Movable> = boxes.values().cloned().collect();
 
         for box_ in boxes {
-            grid_print.modify(box_.points[0], '[');
-            grid_print.modify(box_.points[1], ']');
+            match box_.points.as_slice() {
+                [single] => grid_print.modify(*single, Tile::Box),
+                [left, right] => {
+                    grid_print.modify(*left, Tile::BoxLeft);
+                    grid_print.modify(*right, Tile::BoxRight);
+                }
+                _ => unreachable!("box wider than two cells"),
+            }
         }
 
-        grid_print.modify(robot.points[0], ROBOT);
+        grid_print.modify(robot.points[0], Tile::Robot);
 
-        println!("Move: {:?}", dir);
-        println!("{}", grid_print);
+        grid_print
     }
 }
 
@@ -232,6 +261,7 @@ impl Movable {
 mod tests {
     use crate::solutions::year2024::day15::Day15;
     use crate::solutions::Solution;
+    use crate::utils::render::frames::FrameRecorder;
 
     const SMALL_EXAMPLE: &str = r#"########
 #..O.O.#
@@ -281,6 +311,30 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"#;
         assert_eq!("9021", Day15.part_two(BIG_EXAMPLE));
     }
 
+    #[test]
+    fn visualize_big_example() {
+        let mut recorder = FrameRecorder::new(None);
+        Day15.visualize(BIG_EXAMPLE, &mut recorder).unwrap();
+
+        let frames = recorder.into_frames();
+
+        assert_eq!(700, frames.len());
+        assert_eq!(
+            r#"####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################
+"#,
+            frames.last().unwrap().grid().to_string()
+        );
+    }
+
     #[test]
     fn part_two_reddit_case_2() {
         const INPUT: &str = r#"#######
@@ -1,9 +1,9 @@
 use crate::solutions::Solution;
 use crate::utils::deltoid_surface::DeltoidSurface;
 use crate::utils::grid::Grid;
+use crate::utils::grid_search::{DistanceMap, GridSearch};
 use crate::utils::point::Point;
 use itertools::Itertools;
-use std::collections::HashMap;
 use std::ops::RangeBounds;
 
 pub struct Day20;
@@ -31,8 +31,7 @@ impl Day20 {
     }
 
     fn part_two_cheats_in_range(&self, input: &str, range: impl RangeBounds<usize>) -> usize {
-        let cheat_range_from_current =
-            |current: Point| DeltoidSurface::new(current, 20).points().into_iter();
+        let cheat_range_from_current = |current: Point| DeltoidSurface::new(current, 20).iter();
 
         self.cheats_in_range(input, range, &cheat_range_from_current)
     }
@@ -49,9 +48,7 @@ impl Day20 {
     {
         let grid: Grid<char> = Grid::from(input);
         let start = grid.get_first_position(&'S').unwrap();
-        let end = grid.get_first_position(&'E').unwrap();
-
-        let path_without_cheats = self.get_path(start, end, &grid);
+        let path_without_cheats = self.get_path(start, &grid);
 
         path_without_cheats
             .path()
@@ -84,46 +81,25 @@ impl Day20 {
             .count()
     }
 
-    fn get_path(&self, start: Point, end: Point, grid: &Grid<char>) -> PathWithoutCheats {
-        let mut current = start;
-        let mut index: usize = 0;
-        let mut path: HashMap<Point, usize> = HashMap::new();
-
-        while current != end {
-            let tmp = current.adjacent();
-            let next = tmp
-                .iter()
-                .find(|p| {
-                    !path.contains_key(p)
-                        && grid
-                            .get_for_point(p)
-                            .is_some_and(|element| ['.', 'E'].contains(element))
-                })
-                .unwrap();
-
-            index += 1;
-            path.insert(current, index);
-            current = *next;
-        }
-
-        index += 1;
-        path.insert(current, index);
+    fn get_path(&self, start: Point, grid: &Grid<char>) -> PathWithoutCheats {
+        let distances = GridSearch::passable(grid, |_, element| ['.', 'E'].contains(element))
+            .distances([start]);
 
-        PathWithoutCheats { path }
+        PathWithoutCheats { distances }
     }
 }
 
 struct PathWithoutCheats {
-    path: HashMap<Point, usize>,
+    distances: DistanceMap,
 }
 
 impl PathWithoutCheats {
     fn picoseconds_from(&self, point: Point) -> Option<usize> {
-        self.path.get(&point).copied()
+        self.distances.distance(&point)
     }
 
     fn path(&self) -> Vec<(usize, Point)> {
-        self.path
+        self.distances
             .iter()
             .map(|(point, time)| (*time, *point))
             .collect()
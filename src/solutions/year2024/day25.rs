@@ -1,6 +1,7 @@
 use crate::solutions::year2024::day25::LockAndKey::{Key, Lock};
 use crate::solutions::Solution;
 use crate::utils::grid::Grid;
+use crate::utils::input_sections::InputSections;
 use std::str::FromStr;
 
 const MAX_HEIGHT: u8 = 6;
@@ -9,9 +10,9 @@ pub struct Day25;
 
 impl Solution for Day25 {
     fn part_one(&self, input: &str) -> String {
-        let items: Vec<LockAndKey> = input
-            .split_terminator("\n\n")
-            .map(|item| item.parse().unwrap())
+        let items: Vec<LockAndKey> = InputSections::from(input)
+            .iter()
+            .map(|section| section.text().parse().unwrap())
             .collect();
 
         let locks = Self::filter_items(&items, |item| matches!(item, Lock(_)));
@@ -145,4 +146,11 @@ mod tests {
     fn part_one_example() {
         assert_eq!("3", Day25.part_one(EXAMPLE));
     }
+
+    #[test]
+    fn part_one_windows_line_endings() {
+        let input = format!("{}\n\n", EXAMPLE).replace('\n', "\r\n");
+
+        assert_eq!("3", Day25.part_one(&input));
+    }
 }
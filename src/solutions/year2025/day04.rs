@@ -1,23 +1,29 @@
 use crate::solutions::Solution;
+use crate::utils::cell_legend::cell_legend;
 use crate::utils::grid::Grid;
 use crate::utils::point::Point;
 use itertools::Itertools;
 
-const ROLL_OF_PAPER: char = '@';
-const REMOVED: char = 'X';
+cell_legend! {
+    enum Tile {
+        Empty = '.',
+        RollOfPaper = '@',
+        Removed = 'X',
+    }
+}
 
 pub struct Day04;
 
 impl Solution for Day04 {
     fn part_one(&self, input: &str) -> String {
-        let grid: Grid<char> = Grid::from(input);
+        let grid: Grid<Tile> = Grid::from(input);
 
-        grid.get_all_positions(&ROLL_OF_PAPER)
+        grid.get_all_positions(&Tile::RollOfPaper)
             .iter()
             .filter(|p| {
                 p.adjacent_with_diagonal_vectors()
                     .iter()
-                    .filter(|adj| grid.is_for_point(&adj.position(), ROLL_OF_PAPER))
+                    .filter(|adj| grid.is_for_point(&adj.position(), Tile::RollOfPaper))
                     .count()
                     < 4
             })
@@ -26,9 +32,9 @@ impl Solution for Day04 {
     }
 
     fn part_two(&self, input: &str) -> String {
-        let mut grid: Grid<char> = Grid::from(input);
+        let mut grid: Grid<Tile> = Grid::from(input);
 
-        grid.get_all_positions(&ROLL_OF_PAPER)
+        grid.get_all_positions(&Tile::RollOfPaper)
             .iter()
             .fold(0, |acc, roll| acc + Self::try_to_remove(&mut grid, roll))
             .to_string()
@@ -36,11 +42,11 @@ impl Solution for Day04 {
 }
 
 impl Day04 {
-    fn try_to_remove(grid: &mut Grid<char>, roll: &Point) -> u32 {
+    fn try_to_remove(grid: &mut Grid<Tile>, roll: &Point) -> u32 {
         let mut removed_count = 0u32;
         if grid
             .get_for_point(roll)
-            .is_some_and(|e| *e != ROLL_OF_PAPER)
+            .is_some_and(|e| *e != Tile::RollOfPaper)
         {
             return removed_count;
         }
@@ -48,11 +54,11 @@ impl Day04 {
         let adjacent = roll.adjacent_with_diagonal_vectors();
         let adjacent_rolls = adjacent
             .iter()
-            .filter(|adj| grid.is_for_point(&adj.position(), ROLL_OF_PAPER))
+            .filter(|adj| grid.is_for_point(&adj.position(), Tile::RollOfPaper))
             .collect_vec();
 
         if adjacent_rolls.len() < 4 {
-            grid.modify(*roll, REMOVED);
+            grid.modify(*roll, Tile::Removed);
             removed_count += 1;
 
             for adj_roll in adjacent_rolls {
@@ -1,57 +1,41 @@
 use crate::solutions::Solution;
+use crate::utils::input_sections::{InputSections, Section};
 use crate::utils::range::Range;
+use crate::utils::range_set::RangeSet;
 use itertools::Itertools;
-use std::collections::VecDeque;
-use std::ops::RangeInclusive;
 
 pub struct Day05;
 
 impl Solution for Day05 {
     fn part_one(&self, input: &str) -> String {
         let (ranges, ids) = self.parse(input);
+        let fresh: RangeSet = ranges.into_iter().collect();
 
         ids.iter()
-            .filter(|id| ranges.iter().any(|range| range.contains(id)))
+            .filter(|id| fresh.contains(**id))
             .count()
             .to_string()
     }
 
     fn part_two(&self, input: &str) -> String {
-        let ranges = self.parse_ranges(input);
+        let (ranges, _) = self.parse(input);
 
         self.unique_ids(ranges.into_iter()).to_string()
     }
 }
 
 impl Day05 {
-    fn parse(&self, input: &str) -> (Vec<RangeInclusive<usize>>, Vec<usize>) {
-        let (ranges_str, ids_str) = input.split_once("\n\n").unwrap();
+    fn parse(&self, input: &str) -> (Vec<Range>, Vec<isize>) {
+        let sections = InputSections::from(input);
+        let (ranges, ids) = sections.pair().unwrap();
 
-        let ranges = ranges_str
-            .lines()
-            .map(|line| {
-                let (start, end) = line.split_once("-").unwrap();
-
-                let start = start.parse::<usize>().unwrap();
-                let end = end.parse::<usize>().unwrap();
-
-                start..=end
-            })
-            .collect_vec();
-
-        let ids = ids_str
-            .lines()
-            .map(|line| line.parse::<usize>().unwrap())
-            .collect_vec();
-
-        (ranges, ids)
+        (self.parse_ranges(ranges), ids.parse_lines().unwrap())
     }
 
-    fn parse_ranges(&self, input: &str) -> Vec<Range> {
-        let (ranges_str, _) = input.split_once("\n\n").unwrap();
-
-        ranges_str
+    fn parse_ranges(&self, ranges: &Section) -> Vec<Range> {
+        ranges
             .lines()
+            .iter()
             .map(|line| {
                 let tuple = line.split_once("-").unwrap();
 
@@ -61,16 +45,7 @@ impl Day05 {
     }
 
     fn unique_ids(&self, ranges: impl Iterator<Item = Range>) -> isize {
-        let mut queue = VecDeque::from_iter(ranges);
-        let mut result: Vec<Range> = Vec::new();
-
-        while let Some(range) = queue.pop_front() {
-            result.push(range);
-
-            queue = queue.iter().flat_map(|other| other.diff(&range)).collect();
-        }
-
-        result.iter().map(|range| range.len()).sum::<isize>()
+        ranges.collect::<RangeSet>().len()
     }
 }
 
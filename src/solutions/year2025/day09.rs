@@ -1,9 +1,7 @@
 use crate::solutions::Solution;
-use crate::utils::filled_region::FilledRegion;
 use crate::utils::point::Point;
 use crate::utils::polygon::Polygon;
 use crate::utils::surface_range::SurfaceRange;
-use crate::utils::traits::IsInside;
 use itertools::Itertools;
 
 pub struct Day09;
@@ -20,18 +18,12 @@ impl Solution for Day09 {
 
     fn part_two(&self, input: &str) -> String {
         let points = self.parse(input);
-        let region: FilledRegion = points.clone().collect::<Polygon>().into();
+        let mask = points.clone().collect::<Polygon>().mask();
 
         points
             .tuple_combinations()
-            .filter_map(|(a, b)| {
-                let rectangle = Polygon::rectangle(a, b);
-                if region.is_inside(&rectangle) {
-                    return Some(SurfaceRange::from((a, b)).area());
-                }
-
-                None
-            })
+            .filter(|(a, b)| mask.contains_rectangle(*a, *b))
+            .map(|(a, b)| SurfaceRange::from((a, b)).area())
             .max()
             .unwrap()
             .to_string()
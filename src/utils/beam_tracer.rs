@@ -0,0 +1,395 @@
+use crate::utils::direction::Direction;
+use crate::utils::direction::Direction::{East, North, South, West};
+use crate::utils::grid_like::GridLike;
+use crate::utils::point::Point;
+use crate::utils::surface_range::SurfaceRange;
+use crate::utils::vector::Vector;
+use std::collections::{HashSet, VecDeque};
+
+/// What happens to a beam entering an obstacle cell
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BeamAction {
+    /// Goes through without changing direction
+    Pass,
+    /// Leaves the cell in another direction, like after hitting a mirror
+    Turn(Direction),
+    Split(Direction, Direction),
+    /// Does not enter the cell, turns to the direction in the previous cell instead
+    Blocked(Direction),
+    /// Stops in the cell
+    #[allow(dead_code)]
+    Absorb,
+}
+
+/// Beams travelling in four main directions over a grid.
+/// Jumps to the next obstacle are precomputed, so beams skip empty cells at once.
+pub struct BeamTracer<'a, G>
+where
+    G: GridLike,
+{
+    grid: &'a G,
+    surface: SurfaceRange,
+    obstacles: Vec<bool>,
+    jumps: Vec<[Option<Point>; 4]>,
+}
+
+impl<'a, G> BeamTracer<'a, G>
+where
+    G: GridLike,
+{
+    /// Cells accepted by `is_obstacle` are the only ones the beam action is asked about.
+    /// Panics for an empty grid, beams have nowhere to travel.
+    pub fn new<F>(grid: &'a G, is_obstacle: F) -> Self
+    where
+        F: Fn(&G::Cell) -> bool,
+    {
+        let surface = grid
+            .surface()
+            .expect("Cannot trace beams over an empty grid");
+        let mut tracer = Self {
+            grid,
+            surface,
+            obstacles: vec![false; surface.area()],
+            jumps: vec![[None; 4]; surface.area()],
+        };
+
+        for point in surface.points() {
+            let index = tracer.index(&point);
+            tracer.obstacles[index] = grid.get_for_point(&point).is_some_and(&is_obstacle);
+        }
+        let columns: Vec<isize> = surface.columns().iter().collect();
+        let rows: Vec<isize> = surface.rows().iter().collect();
+
+        for (direction, lines, along) in [
+            (North, &columns, rows.clone()),
+            (South, &columns, rows.iter().rev().copied().collect()),
+            (West, &rows, columns.clone()),
+            (
+                East,
+                &rows,
+                columns.iter().rev().copied().collect::<Vec<_>>(),
+            ),
+        ] {
+            for line in lines {
+                let mut obstacle: Option<Point> = None;
+
+                for position in &along {
+                    let point = match direction {
+                        North | South => Point::new(*line, *position),
+                        _ => Point::new(*position, *line),
+                    };
+
+                    let index = tracer.index(&point);
+                    tracer.jumps[index][Self::slot(direction)] = obstacle;
+
+                    if tracer.obstacles[index] {
+                        obstacle = Some(point);
+                    }
+                }
+            }
+        }
+
+        tracer
+    }
+
+    /// The closest obstacle strictly after the point in the direction, None if the beam leaves the grid
+    pub fn jump(&self, point: &Point, direction: Direction) -> Option<Point> {
+        self.jumps[self.index(point)][Self::slot(direction)]
+    }
+
+    pub fn trace<F>(&self, start: Vector, action: F) -> BeamTrace
+    where
+        F: Fn(&Vector, &G::Cell) -> BeamAction,
+    {
+        self.trace_beams([start], None, action)
+    }
+
+    /// Trace as if the cell at the point was replaced by the given one, which is an obstacle
+    pub fn trace_with_override<F>(
+        &self,
+        start: Vector,
+        point: Point,
+        cell: &G::Cell,
+        action: F,
+    ) -> BeamTrace
+    where
+        F: Fn(&Vector, &G::Cell) -> BeamAction,
+    {
+        self.trace_beams([start], Some((point, cell)), action)
+    }
+
+    /// Every start is a beam entering the given cell, so its action applies too
+    pub fn trace_beams<I, F>(
+        &self,
+        starts: I,
+        cell_override: Option<(Point, &G::Cell)>,
+        action: F,
+    ) -> BeamTrace
+    where
+        I: IntoIterator<Item = Vector>,
+        F: Fn(&Vector, &G::Cell) -> BeamAction,
+    {
+        let mut trace = BeamTrace {
+            surface: self.surface,
+            energised: vec![false; self.surface.area()],
+            energised_count: 0,
+            exits: Vec::new(),
+            looped: false,
+        };
+        let mut visited: HashSet<Vector> = HashSet::new();
+        let mut beams: VecDeque<Vector> = starts
+            .into_iter()
+            .filter(|start| self.surface.contains(start.position()))
+            .collect();
+
+        while let Some(beam) = beams.pop_front() {
+            let point = beam.position();
+            let facing = beam.facing();
+
+            let cell = match cell_override {
+                Some((p, cell)) if p == point => Some(cell),
+                _ => self.grid.get_for_point(&point),
+            };
+
+            let beam_action = match cell {
+                Some(cell) if self.is_stop(&point, cell_override) => {
+                    if !visited.insert(beam) {
+                        trace.looped = true;
+                        continue;
+                    }
+
+                    action(&beam, cell)
+                }
+                _ => BeamAction::Pass,
+            };
+
+            let outgoing = match beam_action {
+                BeamAction::Pass => [Some((point, facing)), None],
+                BeamAction::Turn(direction) => [Some((point, direction)), None],
+                BeamAction::Split(first, second) => [Some((point, first)), Some((point, second))],
+                BeamAction::Blocked(direction) => {
+                    let previous = point.move_in(facing.opposite());
+                    if !self.surface.contains(previous) {
+                        continue;
+                    }
+
+                    [Some((previous, direction)), None]
+                }
+                BeamAction::Absorb => [None, None],
+            };
+
+            if !matches!(beam_action, BeamAction::Blocked(_)) {
+                trace.energise(&point);
+            }
+
+            for (from, direction) in outgoing.into_iter().flatten() {
+                let next = self.next_stop(&from, direction, cell_override);
+                let mut current = from.move_in(direction);
+
+                while self.surface.contains(current) && Some(current) != next {
+                    trace.energise(&current);
+                    current = current.move_in(direction);
+                }
+
+                match next {
+                    Some(next) => beams.push_back(Vector::new(next, direction)),
+                    None => trace.exits.push(Vector::new(
+                        current.move_in(direction.opposite()),
+                        direction,
+                    )),
+                }
+            }
+        }
+
+        trace
+    }
+
+    fn is_stop(&self, point: &Point, cell_override: Option<(Point, &G::Cell)>) -> bool {
+        cell_override.is_some_and(|(p, _)| p == *point) || self.obstacles[self.index(point)]
+    }
+
+    fn next_stop(
+        &self,
+        from: &Point,
+        direction: Direction,
+        cell_override: Option<(Point, &G::Cell)>,
+    ) -> Option<Point> {
+        let next = self.jump(from, direction);
+
+        let Some((overridden, _)) = cell_override else {
+            return next;
+        };
+
+        let ahead = |p: &Point| match direction {
+            North => p.x == from.x && p.y < from.y,
+            South => p.x == from.x && p.y > from.y,
+            West => p.y == from.y && p.x < from.x,
+            East => p.y == from.y && p.x > from.x,
+            _ => false,
+        };
+
+        match next {
+            _ if !ahead(&overridden) => next,
+            Some(next) if from.manhattan_distance(&next) < from.manhattan_distance(&overridden) => {
+                Some(next)
+            }
+            _ => Some(overridden),
+        }
+    }
+
+    fn index(&self, point: &Point) -> usize {
+        let top_left = self.surface.top_left_corner();
+
+        ((point.y - top_left.y) * self.surface.columns().len() + point.x - top_left.x) as usize
+    }
+
+    fn slot(direction: Direction) -> usize {
+        match direction {
+            North => 0,
+            East => 1,
+            South => 2,
+            West => 3,
+            _ => panic!("Beams move only in four main directions, not {}", direction),
+        }
+    }
+}
+
+/// Result of tracing beams: cells they went through, where they left the grid
+/// and whether some beam came back to a state it was already in
+#[derive(Debug)]
+pub struct BeamTrace {
+    surface: SurfaceRange,
+    energised: Vec<bool>,
+    energised_count: usize,
+    exits: Vec<Vector>,
+    looped: bool,
+}
+
+impl BeamTrace {
+    pub fn energised_count(&self) -> usize {
+        self.energised_count
+    }
+
+    pub fn is_energised(&self, point: &Point) -> bool {
+        self.surface.contains(*point) && self.energised[self.index(point)]
+    }
+
+    pub fn energised(&self) -> impl Iterator<Item = Point> + '_ {
+        self.surface
+            .points()
+            .into_iter()
+            .filter(|p| self.is_energised(p))
+    }
+
+    /// Last cells inside the grid with the direction in which beams left
+    #[allow(dead_code)]
+    pub fn exits(&self) -> &[Vector] {
+        &self.exits
+    }
+
+    pub fn looped(&self) -> bool {
+        self.looped
+    }
+
+    fn energise(&mut self, point: &Point) {
+        let index = self.index(point);
+
+        if !self.energised[index] {
+            self.energised[index] = true;
+            self.energised_count += 1;
+        }
+    }
+
+    fn index(&self, point: &Point) -> usize {
+        let top_left = self.surface.top_left_corner();
+
+        ((point.y - top_left.y) * self.surface.columns().len() + point.x - top_left.x) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::beam_tracer::{BeamAction, BeamTracer};
+    use crate::utils::direction::Direction::{East, North, South, West};
+    use crate::utils::grid::Grid;
+    use crate::utils::point::Point;
+    use crate::utils::vector::Vector;
+
+    const ROOM: &str = ".#...
+....#
+.....
+.....
+...#.";
+
+    fn walker(vector: &Vector, _: &char) -> BeamAction {
+        BeamAction::Blocked(vector.facing().cw())
+    }
+
+    #[test]
+    fn jump_tables() {
+        let grid: Grid<char> = Grid::from(ROOM);
+        let tracer = BeamTracer::new(&grid, |c| *c == '#');
+
+        assert_eq!(
+            Some(Point::new(1, 0)),
+            tracer.jump(&Point::new(1, 3), North)
+        );
+        assert_eq!(Some(Point::new(4, 1)), tracer.jump(&Point::new(0, 1), East));
+        assert_eq!(
+            Some(Point::new(3, 4)),
+            tracer.jump(&Point::new(3, 0), South)
+        );
+        assert_eq!(None, tracer.jump(&Point::new(2, 2), West));
+    }
+
+    #[test]
+    fn blocked_beam_leaves_the_grid() {
+        let grid: Grid<char> = Grid::from(ROOM);
+        let tracer = BeamTracer::new(&grid, |c| *c == '#');
+
+        let trace = tracer.trace(Vector::new(Point::new(1, 3), North), walker);
+
+        assert!(!trace.looped());
+        assert_eq!(vec![Vector::new(Point::new(0, 3), West)], trace.exits());
+        assert_eq!(9, trace.energised_count());
+        assert!(!trace.is_energised(&Point::new(1, 0)));
+        assert!(trace.is_energised(&Point::new(3, 3)));
+    }
+
+    #[test]
+    fn loop_with_extra_obstacle() {
+        let grid: Grid<char> = Grid::from(ROOM);
+        let tracer = BeamTracer::new(&grid, |c| *c == '#');
+        let start = Vector::new(Point::new(1, 3), North);
+
+        let trace = tracer.trace_with_override(start, Point::new(0, 3), &'#', walker);
+
+        assert!(trace.looped());
+        assert!(trace.exits().is_empty());
+        assert_eq!(8, trace.energised_count());
+    }
+
+    #[test]
+    fn mirrors_and_splitters() {
+        let grid: Grid<char> = Grid::from(".\\.\n.-.\n#..");
+        let tracer = BeamTracer::new(&grid, |c| *c != '.');
+
+        let trace = tracer.trace(Vector::new(Point::new(0, 0), East), |beam, c| {
+            match (c, beam.facing()) {
+                ('\\', East) => BeamAction::Turn(South),
+                ('-', North | South) => BeamAction::Split(West, East),
+                ('#', _) => BeamAction::Absorb,
+                _ => BeamAction::Pass,
+            }
+        });
+
+        assert_eq!(5, trace.energised_count());
+        assert_eq!(
+            vec![
+                Vector::new(Point::new(0, 1), West),
+                Vector::new(Point::new(2, 1), East)
+            ],
+            trace.exits()
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::utils::grid::Grid;
+use crate::utils::point::Point;
+use std::str::FromStr;
+
+/// Two-way mapping between grid cells and characters of the puzzle input
+pub trait CellLegend: Sized {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+/// Declares a cell enum together with its character legend:
+///
+/// ```ignore
+/// cell_legend! {
+///     enum Tile {
+///         Empty = '.',
+///         Wall = '#',
+///     }
+/// }
+/// ```
+///
+/// Besides `CellLegend` it implements `From<char>`, which panics on unknown characters
+/// so `Grid::from` keeps working, and `Display` printing the character back.
+macro_rules! cell_legend {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident = $c:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($variant),+
+        }
+
+        impl $crate::utils::cell_legend::CellLegend for $name {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    $($c => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $(Self::$variant => $c,)+
+                }
+            }
+        }
+
+        impl From<char> for $name {
+            fn from(c: char) -> Self {
+                <Self as $crate::utils::cell_legend::CellLegend>::from_char(c)
+                    .unwrap_or_else(|| panic!("Unknown {} cell '{}'", stringify!($name), c))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    "{}",
+                    <Self as $crate::utils::cell_legend::CellLegend>::to_char(self)
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use cell_legend;
+
+/// Every character is a cell of its own
+impl CellLegend for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Fails on the first unknown character with its 1-based line and column
+impl<T> FromStr for Grid<T>
+where
+    T: CellLegend + PartialEq,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells: Vec<(Point, T)> = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let cell = T::from_char(c).ok_or_else(|| {
+                    format!("Unknown cell '{}' at line {}, column {}", c, y + 1, x + 1)
+                })?;
+
+                cells.push((Point::new(x as isize, y as isize), cell));
+            }
+        }
+
+        Ok(cells.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::cell_legend::CellLegend;
+    use crate::utils::grid::Grid;
+
+    cell_legend! {
+        enum Tile {
+            Empty = '.',
+            Wall = '#',
+            Start = 'S',
+        }
+    }
+
+    #[test]
+    fn legend() {
+        assert_eq!(Some(Tile::Wall), Tile::from_char('#'));
+        assert_eq!(None, Tile::from_char('x'));
+        assert_eq!('S', Tile::Start.to_char());
+        assert_eq!(Tile::Empty, Tile::from('.'));
+        assert_eq!("#", Tile::Wall.to_string());
+    }
+
+    #[test]
+    fn parse_grid() {
+        let grid: Grid<Tile> = "#.S\n.##".parse().unwrap();
+
+        assert_eq!(Some(&Tile::Start), grid.get(2, 0));
+        assert_eq!("#.S\n.##\n", grid.to_string());
+    }
+
+    #[test]
+    fn parse_grid_error() {
+        let result: Result<Grid<Tile>, String> = "#.S\n.#x".parse();
+
+        assert_eq!(
+            Err("Unknown cell 'x' at line 2, column 3".to_string()),
+            result.map(|g| g.to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown Tile cell 'x'")]
+    fn unknown_char() {
+        let _ = Tile::from('x');
+    }
+
+    #[test]
+    fn grid_from_str() {
+        let grid: Grid<Tile> = Grid::from("S#");
+
+        assert_eq!(Some(&Tile::Start), grid.get(0, 0));
+        assert_eq!(Some(&Tile::Wall), grid.get(1, 0));
+    }
+}
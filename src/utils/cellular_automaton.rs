@@ -0,0 +1,463 @@
+use crate::utils::direction::Direction;
+use crate::utils::grid_like::{GridLike, GridView};
+use crate::utils::grid_search::Neighbourhood;
+use crate::utils::point::Point;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::mem;
+use std::str::FromStr;
+
+/// Birth and survival rule of two-state automata, written like `B3/S23` for Conway's Life.
+/// Neighbour counts up to 127 are supported, enough for 4D Moore neighbourhoods.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LifeRule {
+    birth: u128,
+    survive: u128,
+}
+
+impl LifeRule {
+    pub const CONWAY: Self = Self {
+        birth: 1 << 3,
+        survive: (1 << 2) | (1 << 3),
+    };
+
+    pub fn new(birth: &[usize], survive: &[usize]) -> Self {
+        let mask = |counts: &[usize]| {
+            counts.iter().fold(0u128, |mask, &c| {
+                assert!(c < 128, "Neighbour count {} is above the supported 127", c);
+
+                mask | (1 << c)
+            })
+        };
+
+        Self {
+            birth: mask(birth),
+            survive: mask(survive),
+        }
+    }
+
+    pub fn next(&self, alive: bool, alive_neighbours: usize) -> bool {
+        let mask = if alive { self.survive } else { self.birth };
+
+        alive_neighbours < 128 && mask & (1 << alive_neighbours) != 0
+    }
+
+    /// Transition for `GridAutomaton` over cells which are either `alive` or `dead`
+    pub fn transition<G>(
+        &self,
+        alive: G::Cell,
+        dead: G::Cell,
+    ) -> impl Fn(&G::Cell, Neighbours<'_, G>) -> G::Cell
+    where
+        G: GridView,
+        G::Cell: Clone + PartialEq,
+    {
+        let rule = *self;
+
+        move |cell: &G::Cell, neighbours: Neighbours<'_, G>| {
+            let count = neighbours.filter(|n| **n == alive).count();
+
+            if rule.next(*cell == alive, count) {
+                alive.clone()
+            } else {
+                dead.clone()
+            }
+        }
+    }
+}
+
+impl FromStr for LifeRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (birth, survive) = s
+            .split_once('/')
+            .and_then(|(b, s)| Some((b.strip_prefix('B')?, s.strip_prefix('S')?)))
+            .ok_or_else(|| format!("Invalid rule '{}', expected format like B3/S23", s))?;
+
+        let counts = |digits: &str| -> Result<Vec<usize>, String> {
+            digits
+                .chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|d| d as usize)
+                        .ok_or_else(|| format!("Invalid neighbour count '{}' in rule '{}'", c, s))
+                })
+                .collect()
+        };
+
+        Ok(Self::new(&counts(birth)?, &counts(survive)?))
+    }
+}
+
+/// Cells around a point, iterated without allocating
+pub struct Neighbours<'a, G: ?Sized> {
+    grid: &'a G,
+    point: Point,
+    directions: std::slice::Iter<'a, Direction>,
+}
+
+impl<'a, G> Iterator for Neighbours<'a, G>
+where
+    G: GridView + ?Sized,
+{
+    type Item = &'a G::Cell;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for direction in self.directions.by_ref() {
+            if let Some(cell) = self
+                .grid
+                .step(&self.point, *direction)
+                .and_then(|next| self.grid.get_for_point(&next))
+            {
+                return Some(cell);
+            }
+        }
+
+        None
+    }
+}
+
+/// Generations repeat from `start` with the given `length`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Automaton over any grid storage, every existing cell is updated in each generation.
+/// Neighbours come from the neighbourhood of the grid unless `with_neighbourhood` says otherwise.
+/// The next generation is written into a second buffer, which is swapped afterwards.
+pub struct GridAutomaton<G>
+where
+    G: GridLike,
+{
+    current: G,
+    next: G,
+    positions: Vec<Point>,
+    neighbourhood: Neighbourhood,
+    fixed: Vec<(Point, G::Cell)>,
+    generation: usize,
+}
+
+impl<G> GridAutomaton<G>
+where
+    G: GridLike + Clone,
+    G::Cell: Clone,
+{
+    pub fn new(grid: G) -> Self {
+        let positions = grid.cells().map(|(point, _)| point).collect();
+        let neighbourhood = grid.neighbourhood();
+
+        Self {
+            next: grid.clone(),
+            current: grid,
+            positions,
+            neighbourhood,
+            fixed: Vec::new(),
+            generation: 0,
+        }
+    }
+
+    pub fn with_neighbourhood(mut self, neighbourhood: Neighbourhood) -> Self {
+        self.neighbourhood = neighbourhood;
+        self
+    }
+
+    /// Cells which keep their value whatever the rule says
+    pub fn with_fixed<I>(mut self, fixed: I) -> Self
+    where
+        I: IntoIterator<Item = (Point, G::Cell)>,
+    {
+        self.fixed = fixed.into_iter().collect();
+        for (point, cell) in &self.fixed {
+            self.current.set_for_point(point, cell.clone());
+        }
+
+        self
+    }
+
+    pub fn grid(&self) -> &G {
+        &self.current
+    }
+
+    #[allow(dead_code)]
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step<R>(&mut self, rule: &R)
+    where
+        R: Fn(&G::Cell, Neighbours<'_, G>) -> G::Cell,
+    {
+        for point in &self.positions {
+            let cell = self.current.get_for_point(point).unwrap();
+            let neighbours = Neighbours {
+                grid: &self.current,
+                point: *point,
+                directions: self.neighbourhood.directions().iter(),
+            };
+
+            self.next.set_for_point(point, rule(cell, neighbours));
+        }
+
+        for (point, cell) in &self.fixed {
+            self.next.set_for_point(point, cell.clone());
+        }
+
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    /// Steps until the given generation
+    pub fn run<R>(&mut self, generation: usize, rule: &R)
+    where
+        R: Fn(&G::Cell, Neighbours<'_, G>) -> G::Cell,
+    {
+        while self.generation < generation {
+            self.step(rule);
+        }
+    }
+
+    /// Like `run`, but once a state repeats the remaining full cycles are skipped.
+    /// Returns the cycle if it was found before reaching the generation.
+    #[allow(dead_code)]
+    pub fn run_with_cycle_detection<R>(&mut self, generation: usize, rule: &R) -> Option<Cycle>
+    where
+        R: Fn(&G::Cell, Neighbours<'_, G>) -> G::Cell,
+        G::Cell: Eq + Hash,
+    {
+        let first_generation = self.generation;
+        let mut seen: HashMap<Vec<G::Cell>, usize> = HashMap::new();
+        let mut history: Vec<Vec<G::Cell>> = Vec::new();
+
+        while self.generation < generation {
+            let state = self.state();
+
+            if let Some(&start) = seen.get(&state) {
+                let length = self.generation - start;
+                let target = start + (generation - start) % length;
+
+                self.load(&history[target - first_generation]);
+                self.generation = generation;
+
+                return Some(Cycle { start, length });
+            }
+
+            seen.insert(state.clone(), self.generation);
+            history.push(state);
+            self.step(rule);
+        }
+
+        None
+    }
+
+    fn state(&self) -> Vec<G::Cell> {
+        self.positions
+            .iter()
+            .map(|p| self.current.get_for_point(p).unwrap().clone())
+            .collect()
+    }
+
+    fn load(&mut self, state: &[G::Cell]) {
+        for (point, cell) in self.positions.iter().zip(state) {
+            self.current.set_for_point(point, cell.clone());
+        }
+    }
+}
+
+/// Two-state automaton on an unbounded space, only alive cells are stored.
+/// Works for any point type with a fixed number of neighbours, like `Point`, `Point3D` or `Hex`.
+pub struct SetAutomaton<P, const N: usize> {
+    alive: HashSet<P>,
+    neighbours: fn(&P) -> [P; N],
+    counts: HashMap<P, usize>,
+    next: HashSet<P>,
+    generation: usize,
+}
+
+impl<P, const N: usize> SetAutomaton<P, N>
+where
+    P: Copy + Eq + Hash,
+{
+    #[allow(dead_code)]
+    pub fn new<I>(alive: I, neighbours: fn(&P) -> [P; N]) -> Self
+    where
+        I: IntoIterator<Item = P>,
+    {
+        Self {
+            alive: alive.into_iter().collect(),
+            neighbours,
+            counts: HashMap::new(),
+            next: HashSet::new(),
+            generation: 0,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn alive(&self) -> &HashSet<P> {
+        &self.alive
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.alive.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.alive.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step(&mut self, rule: &LifeRule) {
+        self.counts.clear();
+        for point in &self.alive {
+            for neighbour in (self.neighbours)(point) {
+                *self.counts.entry(neighbour).or_default() += 1;
+            }
+        }
+
+        self.next.clear();
+        self.next.extend(
+            self.counts
+                .iter()
+                .filter(|(point, count)| rule.next(self.alive.contains(point), **count))
+                .map(|(point, _)| *point),
+        );
+        // alive cells without alive neighbours are not counted above
+        self.next.extend(
+            self.alive
+                .iter()
+                .filter(|point| !self.counts.contains_key(point) && rule.next(true, 0)),
+        );
+
+        mem::swap(&mut self.alive, &mut self.next);
+        self.generation += 1;
+    }
+
+    #[allow(dead_code)]
+    pub fn run(&mut self, generation: usize, rule: &LifeRule) {
+        while self.generation < generation {
+            self.step(rule);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::cellular_automaton::{Cycle, GridAutomaton, LifeRule, SetAutomaton};
+    use crate::utils::grid::Grid;
+    use crate::utils::grid_search::Neighbourhood;
+    use crate::utils::hex::Hex;
+    use crate::utils::light_grid::LightGrid;
+    use crate::utils::point::Point;
+    use crate::utils::point3d::Point3D;
+
+    const GLIDER: &str = ".#...\n..#..\n###..\n.....\n.....";
+
+    #[test]
+    fn parse_rule() {
+        assert_eq!(Ok(LifeRule::CONWAY), "B3/S23".parse());
+        assert_eq!(LifeRule::new(&[2], &[]), "B2/S".parse().unwrap());
+        assert!("3/23".parse::<LifeRule>().is_err());
+        assert!("B3/S2x".parse::<LifeRule>().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Neighbour count 128 is above the supported 127")]
+    fn rule_with_too_many_neighbours() {
+        LifeRule::new(&[3, 128], &[]);
+    }
+
+    #[test]
+    fn blinker_has_period_two() {
+        let grid: LightGrid<char> = ".....\n..#..\n..#..\n..#..\n.....".parse().unwrap();
+        let rule = LifeRule::CONWAY.transition('#', '.');
+        let mut automaton =
+            GridAutomaton::new(grid).with_neighbourhood(Neighbourhood::WithDiagonals);
+
+        let cycle = automaton.run_with_cycle_detection(1_000_001, &rule);
+
+        assert_eq!(
+            Some(Cycle {
+                start: 0,
+                length: 2
+            }),
+            cycle
+        );
+        assert_eq!(1_000_001, automaton.generation());
+        assert_eq!(
+            ".....\n.....\n.###.\n.....\n.....\n",
+            automaton.grid().to_string()
+        );
+    }
+
+    #[test]
+    fn fixed_cells() {
+        let grid: Grid<char> = Grid::from("...\n...\n...");
+        let rule = LifeRule::CONWAY.transition('#', '.');
+        let mut automaton = GridAutomaton::new(grid)
+            .with_neighbourhood(Neighbourhood::WithDiagonals)
+            .with_fixed([(Point::new(0, 0), '#')]);
+
+        automaton.run(3, &rule);
+
+        assert_eq!("#..\n...\n...\n", automaton.grid().to_string());
+    }
+
+    #[test]
+    fn custom_transition_with_grid_neighbourhood() {
+        let grid: Grid<u32> = Grid::from_custom("000\n010\n000", |c| c.to_digit(10).unwrap());
+        let mut automaton = GridAutomaton::new(grid);
+
+        automaton.step(&|cell: &u32, neighbours| cell + neighbours.sum::<u32>());
+
+        assert_eq!("010\n111\n010\n", automaton.grid().to_string());
+    }
+
+    #[test]
+    fn glider_on_grid_and_set() {
+        let grid: Grid<char> = Grid::from(GLIDER);
+        let mut automaton =
+            GridAutomaton::new(grid.clone()).with_neighbourhood(Neighbourhood::WithDiagonals);
+        automaton.run(4, &LifeRule::CONWAY.transition('#', '.'));
+
+        let mut set =
+            SetAutomaton::new(grid.get_all_positions(&'#'), Point::adjacent_with_diagonals);
+        set.run(4, &LifeRule::CONWAY);
+
+        let moved: Vec<Point> = grid
+            .get_all_positions(&'#')
+            .iter()
+            .map(|p| *p + Point::new(1, 1))
+            .collect();
+        assert_eq!(moved.len(), set.len());
+        assert!(moved.iter().all(|p| set.alive().contains(p)));
+        assert_eq!(automaton.grid().get_all_positions(&'#').len(), moved.len());
+        assert!(moved.iter().all(|p| automaton.grid().is_for_point(p, '#')));
+    }
+
+    #[test]
+    fn set_automaton_in_3d_and_hex() {
+        let block = [
+            Point3D::new(0, 0, 0),
+            Point3D::new(1, 0, 0),
+            Point3D::new(0, 1, 0),
+            Point3D::new(1, 1, 0),
+        ];
+        let mut cube = SetAutomaton::new(block, Point3D::adjacent_with_diagonals);
+        cube.run(1, &"B4/S3".parse().unwrap());
+
+        assert_eq!(12, cube.len());
+
+        let mut hexes = SetAutomaton::new([Hex::ORIGIN], Hex::neighbours);
+        hexes.run(1, &LifeRule::new(&[1], &[0]));
+
+        assert_eq!(7, hexes.len());
+    }
+}
@@ -0,0 +1,270 @@
+use crate::utils::light_grid::LightGrid;
+use crate::utils::point::Point;
+use crate::utils::range::Range;
+use crate::utils::surface_range::SurfaceRange;
+use itertools::Itertools;
+
+/// Distinct values on one axis mapped to dense indexes.
+/// Every value gets its own index, and the gaps between values are squeezed into a single
+/// index each, so every index stands for a contiguous run of real values.
+/// Runs end one past their last value, so isize::MAX itself cannot be compressed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressedAxis {
+    breakpoints: Vec<isize>,
+}
+
+impl CompressedAxis {
+    pub fn from_values<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = isize>,
+    {
+        Self::from_bounds(values.into_iter().flat_map(|v| [v, Self::past(v)]))
+    }
+
+    /// Only bounds of the ranges are kept, so values inside a range share an index
+    #[allow(dead_code)]
+    pub fn from_ranges<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = Range>,
+    {
+        Self::from_bounds(
+            ranges
+                .into_iter()
+                .flat_map(|r| [r.start(), Self::past(r.end())]),
+        )
+    }
+
+    fn past(value: isize) -> isize {
+        value
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("Cannot compress value {}, it has no successor", value))
+    }
+
+    fn from_bounds<I>(bounds: I) -> Self
+    where
+        I: IntoIterator<Item = isize>,
+    {
+        let breakpoints: Vec<isize> = bounds.into_iter().sorted().dedup().collect();
+        assert!(
+            !breakpoints.is_empty(),
+            "Cannot compress an axis without values"
+        );
+
+        Self { breakpoints }
+    }
+
+    /// Number of indexes
+    pub fn len(&self) -> usize {
+        self.breakpoints.len() - 1
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Index of the run containing the value, None outside of the compressed values
+    pub fn index(&self, value: isize) -> Option<usize> {
+        if value < self.breakpoints[0] || value >= self.breakpoints[self.len()] {
+            return None;
+        }
+
+        Some(self.breakpoints.partition_point(|&b| b <= value) - 1)
+    }
+
+    /// Real values behind the index
+    pub fn span(&self, index: usize) -> Range {
+        Range::new(self.breakpoints[index], self.breakpoints[index + 1] - 1).unwrap()
+    }
+
+    /// How many real values are behind the index
+    pub fn size(&self, index: usize) -> isize {
+        self.breakpoints[index + 1] - self.breakpoints[index]
+    }
+}
+
+/// Cell of a compressed grid remembering how much of the real plane it covers
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CompressedCell<T> {
+    pub value: T,
+    pub width: isize,
+    pub height: isize,
+}
+
+impl<T> CompressedCell<T> {
+    pub fn area(&self) -> isize {
+        self.width * self.height
+    }
+}
+
+/// Points with large coordinates mapped onto a small grid, one compressed axis per dimension
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoordinateCompression {
+    x: CompressedAxis,
+    y: CompressedAxis,
+}
+
+impl CoordinateCompression {
+    pub fn new(x: CompressedAxis, y: CompressedAxis) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_points<'a, I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Point>,
+        I::IntoIter: Clone,
+    {
+        let points = points.into_iter();
+
+        Self::new(
+            CompressedAxis::from_values(points.clone().map(|p| p.x)),
+            CompressedAxis::from_values(points.map(|p| p.y)),
+        )
+    }
+
+    pub fn x(&self) -> &CompressedAxis {
+        &self.x
+    }
+
+    pub fn y(&self) -> &CompressedAxis {
+        &self.y
+    }
+
+    pub fn width(&self) -> usize {
+        self.x.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.y.len()
+    }
+
+    /// Position in the compressed grid of the cell containing the real point
+    pub fn compress(&self, point: &Point) -> Option<Point> {
+        Some(Point::new(
+            self.x.index(point.x)? as isize,
+            self.y.index(point.y)? as isize,
+        ))
+    }
+
+    /// Real points behind a compressed cell
+    #[allow(dead_code)]
+    pub fn expand(&self, compressed: &Point) -> SurfaceRange {
+        SurfaceRange::new(
+            self.x.span(compressed.x as usize),
+            self.y.span(compressed.y as usize),
+        )
+    }
+
+    pub fn grid<T>(&self, value: T) -> LightGrid<CompressedCell<T>>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.height())
+            .flat_map(|y| (0..self.width()).map(move |x| (x, y)))
+            .map(|(x, y)| CompressedCell {
+                value: value.clone(),
+                width: self.x.size(x),
+                height: self.y.size(y),
+            })
+            .collect();
+
+        LightGrid::new(cells, self.width(), self.height())
+    }
+
+    /// Real area of the cells accepted by the predicate
+    #[allow(dead_code)]
+    pub fn area<T, F>(grid: &LightGrid<CompressedCell<T>>, mut predicate: F) -> isize
+    where
+        T: Clone,
+        F: FnMut(&T) -> bool,
+    {
+        grid.iter()
+            .filter(|(_, _, cell)| predicate(&cell.value))
+            .map(|(_, _, cell)| cell.area())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::coordinate_compression::{CompressedAxis, CoordinateCompression};
+    use crate::utils::point::Point;
+    use crate::utils::range::Range;
+    use crate::utils::surface_range::SurfaceRange;
+
+    #[test]
+    fn axis_from_values() {
+        let axis = CompressedAxis::from_values([100, 5, 1_000_000, 5]);
+
+        assert_eq!(5, axis.len());
+        assert_eq!(None, axis.index(4));
+        assert_eq!(Some(0), axis.index(5));
+        assert_eq!(Some(1), axis.index(6));
+        assert_eq!(Some(1), axis.index(99));
+        assert_eq!(Some(2), axis.index(100));
+        assert_eq!(Some(4), axis.index(1_000_000));
+        assert_eq!(None, axis.index(1_000_001));
+        assert_eq!(Range::new(101, 999_999).unwrap(), axis.span(3));
+        assert_eq!(999_899, axis.size(3));
+    }
+
+    #[test]
+    fn axis_from_ranges() {
+        let axis =
+            CompressedAxis::from_ranges([Range::new(0, 9).unwrap(), Range::new(5, 20).unwrap()]);
+
+        assert_eq!(3, axis.len());
+        assert_eq!(Some(0), axis.index(4));
+        assert_eq!(Some(1), axis.index(9));
+        assert_eq!(Some(2), axis.index(10));
+        assert_eq!(11, axis.size(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "has no successor")]
+    fn axis_with_max_value() {
+        CompressedAxis::from_values([0, isize::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = "has no successor")]
+    fn axis_with_range_ending_at_max() {
+        CompressedAxis::from_ranges([Range::new(0, isize::MAX).unwrap()]);
+    }
+
+    #[test]
+    fn compress_and_expand() {
+        let points = [Point::new(0, 0), Point::new(1000, 10), Point::new(40, 500)];
+        let compression = CoordinateCompression::from_points(&points);
+
+        assert_eq!(5, compression.width());
+        assert_eq!(5, compression.height());
+        assert_eq!(
+            Some(Point::new(4, 2)),
+            compression.compress(&Point::new(1000, 10))
+        );
+        assert_eq!(
+            Some(Point::new(1, 3)),
+            compression.compress(&Point::new(2, 100))
+        );
+        assert_eq!(None, compression.compress(&Point::new(-1, 0)));
+        assert_eq!(
+            SurfaceRange::from_points(1, 39, 11, 499),
+            compression.expand(&Point::new(1, 3))
+        );
+    }
+
+    #[test]
+    fn grid_keeps_real_sizes() {
+        let points = [Point::new(0, 0), Point::new(9, 4)];
+        let compression = CoordinateCompression::from_points(&points);
+        let mut grid = compression.grid(false);
+
+        assert_eq!(50, CoordinateCompression::area(&grid, |_| true));
+
+        grid.get_mut(1, 1).unwrap().value = true;
+        grid.get_mut(2, 2).unwrap().value = true;
+
+        assert_eq!(24 + 1, CoordinateCompression::area(&grid, |v| *v));
+    }
+}
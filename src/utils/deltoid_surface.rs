@@ -1,5 +1,13 @@
+use crate::utils::hyper_box::{BoxSet, HyperBox};
 use crate::utils::point::Point;
+use crate::utils::range::Range;
+use crate::utils::range_set::RangeSet;
+use crate::utils::surface_range::SurfaceRange;
 
+/// All points within the Manhattan distance from the middle point, a diamond on the grid.
+/// Rotated by 45 degrees (u = x + y, v = x - y) it becomes an axis aligned square,
+/// which makes intersections and unions possible without visiting the points.
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct DeltoidSurface {
     point: Point,
     distance: usize,
@@ -10,30 +18,206 @@ impl DeltoidSurface {
         Self { point, distance }
     }
 
+    /// Diamond around the point reaching exactly the other point
+    #[allow(dead_code)]
+    pub fn reaching(point: Point, other: Point) -> Self {
+        Self::new(point, point.manhattan_distance(&other) as usize)
+    }
+
+    #[allow(dead_code)]
+    pub fn center(&self) -> Point {
+        self.point
+    }
+
+    #[allow(dead_code)]
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.point.manhattan_distance(point) <= self.distance as isize
+    }
+
+    pub fn area(&self) -> usize {
+        2 * self.distance.pow(2) + 2 * self.distance + 1
+    }
+
+    /// Columns covered in the given row
+    pub fn row(&self, y: isize) -> Option<Range> {
+        let half_width = self.distance as isize - (y - self.point.y).abs();
+
+        Range::new(self.point.x - half_width, self.point.x + half_width).ok()
+    }
+
+    /// Square in rotated coordinates, see [`rotate`]
+    pub fn rotated(&self) -> HyperBox<2> {
+        let [u, v] = rotate(&self.point);
+        let distance = self.distance as isize;
+
+        HyperBox::new([
+            Range::new(u - distance, u + distance).unwrap(),
+            Range::new(v - distance, v + distance).unwrap(),
+        ])
+    }
+
+    /// Common part of both diamonds in rotated coordinates, which in general is not a diamond
+    pub fn intersection(&self, other: &Self) -> Option<HyperBox<2>> {
+        self.rotated().intersect(&other.rotated())
+    }
+
+    /// Number of points in both diamonds
+    #[allow(dead_code)]
+    pub fn overlap_area(&self, other: &Self) -> u128 {
+        self.intersection(other)
+            .map_or(0, |common| rotated_lattice_points(&common))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Point> {
+        let (center, distance) = (self.point, self.distance as isize);
+
+        (-distance..=distance).flat_map(move |dx| {
+            let height_diff = distance - dx.abs();
+            let x = center.x + dx;
+
+            (center.y - height_diff..=center.y + height_diff).map(move |y| Point::new(x, y))
+        })
+    }
+
+    #[allow(dead_code)]
     pub fn points(&self) -> Vec<Point> {
-        let isize_distance = self.distance as isize;
-        let required_capacity = (2 * self.distance.pow(2)) + 2 * self.distance + 1;
-        let mut points: Vec<Point> = Vec::with_capacity(required_capacity);
+        let mut points: Vec<Point> = Vec::with_capacity(self.area());
+        points.extend(self.iter());
+
+        points
+    }
+}
+
+/// Point in coordinates rotated by 45 degrees: `[x + y, x - y]`
+pub fn rotate(point: &Point) -> [isize; 2] {
+    [point.x + point.y, point.x - point.y]
+}
+
+/// Back from rotated coordinates, None when u and v have different parity
+pub fn unrotate(u: isize, v: isize) -> Option<Point> {
+    if (u - v).rem_euclid(2) != 0 {
+        return None;
+    }
+
+    Some(Point::new((u + v) / 2, (u - v) / 2))
+}
+
+/// Number of grid points inside a box in rotated coordinates, only pairs with equal parity count
+pub fn rotated_lattice_points(rotated: &HyperBox<2>) -> u128 {
+    let evens =
+        |range: Range| (range.end().div_euclid(2) - (range.start() - 1).div_euclid(2)) as u128;
+    let [u, v] = *rotated.axes();
+    let (u_evens, v_evens) = (evens(u), evens(v));
+
+    u_evens * v_evens + (u.len() as u128 - u_evens) * (v.len() as u128 - v_evens)
+}
+
+/// Union of diamonds, kept also as disjoint boxes in rotated coordinates
+#[derive(Debug, Clone, Default)]
+pub struct DeltoidUnion {
+    surfaces: Vec<DeltoidSurface>,
+    rotated: BoxSet<2>,
+}
+
+impl DeltoidUnion {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, surface: DeltoidSurface) {
+        self.rotated.insert(surface.rotated());
+        self.surfaces.push(surface);
+    }
+
+    #[allow(dead_code)]
+    pub fn surfaces(&self) -> &[DeltoidSurface] {
+        &self.surfaces
+    }
 
-        for x in -isize_distance..=isize_distance {
-            let height_diff = isize_distance - x.abs();
-            let x = self.point.x + x;
+    pub fn contains(&self, point: &Point) -> bool {
+        self.surfaces.iter().any(|s| s.contains(point))
+    }
+
+    /// Number of points covered by at least one diamond
+    #[allow(dead_code)]
+    pub fn area(&self) -> u128 {
+        self.rotated.iter().map(rotated_lattice_points).sum()
+    }
+
+    /// Columns covered in the given row
+    #[allow(dead_code)]
+    pub fn row_coverage(&self, y: isize) -> RangeSet {
+        self.surfaces.iter().filter_map(|s| s.row(y)).collect()
+    }
+
+    /// Any point of the bounds which is not covered, when there is at most a handful of them.
+    /// A lonely uncovered point touches diamond edges or the bounds from every side,
+    /// so only crossings of lines just outside the diamonds and the bounds are checked.
+    #[allow(dead_code)]
+    pub fn uncovered_point(&self, bounds: SurfaceRange) -> Option<Point> {
+        let (x, y) = (bounds.x(), bounds.y());
+        let corners = [
+            Point::new(x.start(), y.start()),
+            Point::new(x.end(), y.start()),
+            Point::new(x.start(), y.end()),
+            Point::new(x.end(), y.end()),
+        ];
 
-            for y in self.point.y - height_diff..=self.point.y + height_diff {
-                points.push(Point::new(x, y));
-            }
+        let mut u_lines: Vec<isize> = corners.iter().map(|c| rotate(c)[0]).collect();
+        let mut v_lines: Vec<isize> = corners.iter().map(|c| rotate(c)[1]).collect();
+        for surface in &self.surfaces {
+            let rotated = surface.rotated();
+            let (u, v) = (rotated.axis(0), rotated.axis(1));
+
+            u_lines.extend([u.start() - 1, u.end() + 1]);
+            v_lines.extend([v.start() - 1, v.end() + 1]);
         }
 
-        points
+        let on_edges = u_lines.iter().chain(v_lines.iter()).flat_map(|&line| {
+            [x.start(), x.end()]
+                .into_iter()
+                .flat_map(move |edge| {
+                    [Point::new(edge, line - edge), Point::new(edge, edge - line)]
+                })
+                .chain([y.start(), y.end()].into_iter().flat_map(move |edge| {
+                    [Point::new(line - edge, edge), Point::new(line + edge, edge)]
+                }))
+        });
+        let crossings = u_lines
+            .iter()
+            .flat_map(|&u| v_lines.iter().filter_map(move |&v| unrotate(u, v)));
+
+        corners
+            .into_iter()
+            .chain(on_edges)
+            .chain(crossings)
+            .find(|point| bounds.contains(*point) && !self.contains(point))
+    }
+}
+
+impl FromIterator<DeltoidSurface> for DeltoidUnion {
+    fn from_iter<I: IntoIterator<Item = DeltoidSurface>>(iter: I) -> Self {
+        let mut union = Self::new();
+        for surface in iter {
+            union.insert(surface);
+        }
+
+        union
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::deltoid_surface::DeltoidSurface;
+    use crate::utils::deltoid_surface::{rotate, unrotate, DeltoidSurface, DeltoidUnion};
     use crate::utils::grid::Grid;
     use crate::utils::point::Point;
+    use crate::utils::range::Range;
     use crate::utils::surface_range::SurfaceRange;
+    use std::collections::HashSet;
 
     #[test]
     fn deltoid_surface_visual_test() {
@@ -77,4 +261,95 @@ mod tests {
         assert_eq!(25, DeltoidSurface::new(middle, 3).points().len());
         assert_eq!(41, DeltoidSurface::new(middle, 4).points().len());
     }
+
+    /// Sensors with their closest beacons from 2022 day 15 example
+    const SENSORS: [((isize, isize), (isize, isize)); 14] = [
+        ((2, 18), (-2, 15)),
+        ((9, 16), (10, 16)),
+        ((13, 2), (15, 3)),
+        ((12, 14), (10, 16)),
+        ((10, 20), (10, 16)),
+        ((14, 17), (10, 16)),
+        ((8, 7), (2, 10)),
+        ((2, 0), (2, 10)),
+        ((0, 11), (2, 10)),
+        ((20, 14), (25, 17)),
+        ((17, 20), (21, 22)),
+        ((16, 7), (15, 3)),
+        ((14, 3), (15, 3)),
+        ((20, 1), (15, 3)),
+    ];
+
+    fn sensors() -> DeltoidUnion {
+        SENSORS
+            .iter()
+            .map(|&((sx, sy), (bx, by))| {
+                DeltoidSurface::reaching(Point::new(sx, sy), Point::new(bx, by))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rotation_round_trip() {
+        let point = Point::new(-3, 7);
+        let [u, v] = rotate(&point);
+
+        assert_eq!(Some(point), unrotate(u, v));
+        assert_eq!(None, unrotate(u + 1, v));
+    }
+
+    #[test]
+    fn row_and_contains() {
+        let surface = DeltoidSurface::new(Point::new(4, 3), 2);
+
+        assert_eq!(Some(Range::new(3, 5).unwrap()), surface.row(2));
+        assert_eq!(None, surface.row(6));
+        assert!(surface.contains(&Point::new(5, 4)));
+        assert!(!surface.contains(&Point::new(6, 4)));
+    }
+
+    #[test]
+    fn overlap_area_matches_points() {
+        let a = DeltoidSurface::new(Point::new(0, 0), 5);
+        let b = DeltoidSurface::new(Point::new(4, 3), 4);
+
+        let a_points: HashSet<Point> = a.points().into_iter().collect();
+        let common = b.points().iter().filter(|p| a_points.contains(p)).count();
+
+        assert_eq!(common as u128, a.overlap_area(&b));
+        assert_eq!(
+            0,
+            a.overlap_area(&DeltoidSurface::new(Point::new(20, 0), 3))
+        );
+    }
+
+    #[test]
+    fn union_area_matches_points() {
+        let union = sensors();
+        let points: HashSet<Point> = union.surfaces().iter().flat_map(|s| s.iter()).collect();
+
+        assert_eq!(points.len() as u128, union.area());
+    }
+
+    #[test]
+    fn row_coverage() {
+        let coverage = sensors().row_coverage(10);
+
+        assert_eq!(27, coverage.len());
+        assert_eq!("{(-2, 24)}", coverage.to_string());
+    }
+
+    #[test]
+    fn uncovered_point() {
+        let union = sensors();
+
+        assert_eq!(
+            Some(Point::new(14, 11)),
+            union.uncovered_point(SurfaceRange::square(20))
+        );
+        assert_eq!(
+            None,
+            union.uncovered_point(SurfaceRange::from_points(0, 10, 0, 10))
+        );
+    }
 }
@@ -1,4 +1,5 @@
-use itertools::Itertools;
+use crate::utils::point::Point;
+use crate::utils::rotation::Rotation;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash, PartialOrd, Ord)]
@@ -14,38 +15,131 @@ pub enum Direction {
 }
 
 impl Direction {
-    const DIRECTIONS: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
-    const REVERSED_DIRECTIONS: [Self; 4] = [Self::North, Self::West, Self::South, Self::East];
-    const DIAGONAL_DIRECTIONS: [Self; 4] = [
+    /// Every direction, clockwise from North
+    const ALL: [Self; 8] = [
+        Self::North,
         Self::NorthEast,
+        Self::East,
         Self::SouthEast,
+        Self::South,
         Self::SouthWest,
+        Self::West,
         Self::NorthWest,
     ];
 
+    /// Every direction, clockwise from North
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        Self::ALL.into_iter()
+    }
+
+    /// North, East, South and West
+    pub fn orthogonal() -> impl Iterator<Item = Self> + Clone {
+        Self::all().filter(|direction| !direction.is_diagonal())
+    }
+
+    /// NorthEast, SouthEast, SouthWest and NorthWest
+    pub fn diagonal() -> impl Iterator<Item = Self> + Clone {
+        Self::all().filter(|direction| direction.is_diagonal())
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+
     pub fn cw(&self) -> Self {
-        self.rotate(Self::DIRECTIONS, 1)
+        self.rotate(Rotation::Clockwise)
     }
 
     pub fn ccw(&self) -> Self {
-        self.rotate(Self::REVERSED_DIRECTIONS, 1)
+        self.rotate(Rotation::CounterClockwise)
+    }
+
+    pub fn cw_45(&self) -> Self {
+        self.rotate(Rotation::Clockwise45)
+    }
+
+    pub fn ccw_45(&self) -> Self {
+        self.rotate(Rotation::CounterClockwise45)
     }
 
     pub fn opposite(&self) -> Self {
-        self.rotate(Self::DIRECTIONS, 2)
+        self.rotate(Rotation::Reverse)
+    }
+
+    pub fn rotate(&self, rotation: Rotation) -> Self {
+        Self::ALL[(self.index() + rotation.eighths()) % 8]
+    }
+
+    /// Rotation turning self into the other direction
+    pub fn turn_to(&self, other: Self) -> Rotation {
+        Rotation::from_eighths(other.index() as isize - self.index() as isize)
     }
 
-    fn rotate(&self, directions: [Self; 4], times: usize) -> Self {
-        if Self::DIAGONAL_DIRECTIONS.contains(self) {
-            todo!("Is not supported to rotate diagonal directions")
+    /// Unit step in this direction, y grows southwards
+    pub fn offset(&self) -> Point {
+        match self {
+            Self::North => Point::new(0, -1),
+            Self::NorthEast => Point::new(1, -1),
+            Self::East => Point::new(1, 0),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(0, 1),
+            Self::SouthWest => Point::new(-1, 1),
+            Self::West => Point::new(-1, 0),
+            Self::NorthWest => Point::new(-1, -1),
         }
+    }
 
-        let (i, _) = directions
-            .into_iter()
-            .find_position(|dir| dir == self)
-            .unwrap();
+    /// Arrow drawing the direction, like `^` for North
+    pub fn arrow(&self) -> char {
+        match self {
+            Self::North => '^',
+            Self::NorthEast => '↗',
+            Self::East => '>',
+            Self::SouthEast => '↘',
+            Self::South => 'v',
+            Self::SouthWest => '↙',
+            Self::West => '<',
+            Self::NorthWest => '↖',
+        }
+    }
 
-        directions[(i + times) % 4]
+    /// Position in the clockwise cycle
+    fn index(&self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::NorthEast => 1,
+            Self::East => 2,
+            Self::SouthEast => 3,
+            Self::South => 4,
+            Self::SouthWest => 5,
+            Self::West => 6,
+            Self::NorthWest => 7,
+        }
+    }
+}
+
+impl TryFrom<Point> for Direction {
+    type Error = String;
+
+    fn try_from(offset: Point) -> Result<Self, Self::Error> {
+        Self::all()
+            .find(|direction| direction.offset() == offset)
+            .ok_or(format!("Offset {} is not a unit step", offset))
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /// Accepts arrows (`^>v<`), compass letters (`NESW`) and moves (`UDLR`)
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' | 'U' => Ok(Self::North),
+            '>' | 'E' | 'R' => Ok(Self::East),
+            'v' | 'S' | 'D' => Ok(Self::South),
+            '<' | 'W' | 'L' => Ok(Self::West),
+            _ => Err(format!("Invalid direction '{}'", value)),
+        }
     }
 }
 
@@ -58,6 +152,9 @@ impl Display for Direction {
 #[cfg(test)]
 mod tests {
     use crate::utils::direction::Direction;
+    use crate::utils::point::Point;
+    use crate::utils::rotation::Rotation;
+    use itertools::Itertools;
 
     #[test]
     fn cw() {
@@ -65,6 +162,8 @@ mod tests {
         assert_eq!(Direction::South, Direction::East.cw());
         assert_eq!(Direction::West, Direction::South.cw());
         assert_eq!(Direction::North, Direction::West.cw());
+        assert_eq!(Direction::SouthEast, Direction::NorthEast.cw());
+        assert_eq!(Direction::NorthEast, Direction::NorthWest.cw());
     }
 
     #[test]
@@ -73,6 +172,8 @@ mod tests {
         assert_eq!(Direction::North, Direction::East.ccw());
         assert_eq!(Direction::East, Direction::South.ccw());
         assert_eq!(Direction::South, Direction::West.ccw());
+        assert_eq!(Direction::NorthWest, Direction::NorthEast.ccw());
+        assert_eq!(Direction::SouthEast, Direction::SouthWest.ccw());
     }
 
     #[test]
@@ -81,5 +182,99 @@ mod tests {
         assert_eq!(Direction::North, Direction::South.opposite());
         assert_eq!(Direction::East, Direction::West.opposite());
         assert_eq!(Direction::South, Direction::North.opposite());
+        assert_eq!(Direction::SouthWest, Direction::NorthEast.opposite());
+        assert_eq!(Direction::NorthWest, Direction::SouthEast.opposite());
+    }
+
+    #[test]
+    fn half_steps() {
+        assert_eq!(Direction::NorthEast, Direction::North.cw_45());
+        assert_eq!(Direction::North, Direction::NorthWest.cw_45());
+        assert_eq!(Direction::NorthWest, Direction::North.ccw_45());
+        assert_eq!(Direction::South, Direction::SouthWest.ccw_45());
+
+        for direction in Direction::all() {
+            assert_eq!(direction.cw(), direction.cw_45().cw_45());
+            assert_eq!(direction, direction.rotate(Rotation::None));
+        }
+    }
+
+    #[test]
+    fn turn_to() {
+        assert_eq!(
+            Rotation::Clockwise,
+            Direction::North.turn_to(Direction::East)
+        );
+        assert_eq!(
+            Rotation::CounterClockwise,
+            Direction::North.turn_to(Direction::West)
+        );
+        assert_eq!(Rotation::Reverse, Direction::East.turn_to(Direction::West));
+        assert_eq!(
+            Rotation::Clockwise135,
+            Direction::West.turn_to(Direction::NorthEast)
+        );
+        assert_eq!(Rotation::None, Direction::South.turn_to(Direction::South));
+
+        for (a, b) in Direction::all().cartesian_product(Direction::all()) {
+            assert_eq!(b, a.rotate(a.turn_to(b)));
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(Point::new(0, -1), Direction::North.offset());
+        assert_eq!(Point::new(-1, 1), Direction::SouthWest.offset());
+
+        for direction in Direction::all() {
+            assert_eq!(Ok(direction), Direction::try_from(direction.offset()));
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+        }
+
+        assert_eq!(
+            Err("Offset (2,0) is not a unit step".to_string()),
+            Direction::try_from(Point::new(2, 0))
+        );
+        assert!(Direction::try_from(Point::new(0, 0)).is_err());
+    }
+
+    #[test]
+    fn chars() {
+        assert_eq!(
+            vec![
+                Direction::North,
+                Direction::East,
+                Direction::South,
+                Direction::West
+            ],
+            "^>v<"
+                .chars()
+                .map(|c| Direction::try_from(c).unwrap())
+                .collect_vec()
+        );
+        assert_eq!(
+            "NESW".chars().map(Direction::try_from).collect_vec(),
+            "URDL".chars().map(Direction::try_from).collect_vec()
+        );
+        assert_eq!(
+            "^>v<",
+            Direction::orthogonal()
+                .map(|d| d.arrow())
+                .collect::<String>()
+        );
+        assert_eq!(
+            Err("Invalid direction 'x'".to_string()),
+            Direction::try_from('x')
+        );
+    }
+
+    #[test]
+    fn iteration() {
+        assert_eq!(8, Direction::all().unique().count());
+        assert_eq!(4, Direction::orthogonal().count());
+        assert!(Direction::diagonal().all(|d| d.is_diagonal()));
+        assert!(Direction::all()
+            .zip(Direction::all().cycle().skip(1))
+            .all(|(a, b)| a.cw_45() == b));
     }
 }
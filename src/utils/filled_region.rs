@@ -51,6 +51,7 @@ impl FilledRegion {
         self.points.len()
     }
 
+    #[allow(dead_code)]
     pub fn points(&self) -> HashSet<Point> {
         self.points.clone()
     }
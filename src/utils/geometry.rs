@@ -0,0 +1,321 @@
+use crate::utils::point::Point;
+use crate::utils::rational::Rational;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+/// Turn made when going from `a` through `b` to `c`, in the usual maths orientation (y up).
+/// On a grid where y grows downwards, clockwise and counter-clockwise swap visually.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SegmentIntersection {
+    Disjoint,
+    /// Single common point, may lie between lattice points
+    Point([Rational; 2]),
+    /// Collinear segments sharing more than one point, ends of the common part
+    Overlap(Point, Point),
+}
+
+/// Cross product of `a - origin` and `b - origin`, twice the signed area of the triangle
+pub fn cross(origin: &Point, a: &Point, b: &Point) -> i128 {
+    let (ax, ay) = ((a.x - origin.x) as i128, (a.y - origin.y) as i128);
+    let (bx, by) = ((b.x - origin.x) as i128, (b.y - origin.y) as i128);
+
+    ax * by - ay * bx
+}
+
+pub fn orientation(a: &Point, b: &Point, c: &Point) -> Orientation {
+    match cross(a, b, c).signum() {
+        1 => Orientation::CounterClockwise,
+        -1 => Orientation::Clockwise,
+        _ => Orientation::Collinear,
+    }
+}
+
+pub fn is_on_segment(point: &Point, start: &Point, end: &Point) -> bool {
+    orientation(start, end, point) == Orientation::Collinear
+        && point.x >= start.x.min(end.x)
+        && point.x <= start.x.max(end.x)
+        && point.y >= start.y.min(end.y)
+        && point.y <= start.y.max(end.y)
+}
+
+/// Intersection of segments `a` and `b`, both including their ends
+#[allow(dead_code)]
+pub fn segment_intersection(a: (&Point, &Point), b: (&Point, &Point)) -> SegmentIntersection {
+    let (a1, a2) = a;
+    let (b1, b2) = b;
+    let denominator = cross(&Point::new(0, 0), &(*a2 - *a1), &(*b2 - *b1));
+
+    if denominator == 0 {
+        if orientation(a1, a2, b1) != Orientation::Collinear {
+            return SegmentIntersection::Disjoint;
+        }
+
+        return collinear_overlap(a, b);
+    }
+
+    let t = Rational::new(cross(a1, b1, b2), denominator);
+    let u = Rational::new(cross(a1, b1, a2), denominator);
+    let unit = |value: Rational| Rational::ZERO <= value && value <= Rational::ONE;
+
+    if !unit(t) || !unit(u) {
+        return SegmentIntersection::Disjoint;
+    }
+
+    let along = |from: isize, to: isize| Rational::from(from) + t * Rational::from(to - from);
+
+    SegmentIntersection::Point([along(a1.x, a2.x), along(a1.y, a2.y)])
+}
+
+#[allow(dead_code)]
+fn collinear_overlap(a: (&Point, &Point), b: (&Point, &Point)) -> SegmentIntersection {
+    // points on a common line are ordered by x, or by y for vertical lines
+    let key = |p: &Point| (p.x, p.y);
+    let (a1, a2) = if key(a.0) <= key(a.1) { a } else { (a.1, a.0) };
+    let (b1, b2) = if key(b.0) <= key(b.1) { b } else { (b.1, b.0) };
+
+    let start = if key(a1) >= key(b1) { a1 } else { b1 };
+    let end = if key(a2) <= key(b2) { a2 } else { b2 };
+
+    match key(start).cmp(&key(end)) {
+        Ordering::Greater => SegmentIntersection::Disjoint,
+        Ordering::Equal => {
+            SegmentIntersection::Point([Rational::from(start.x), Rational::from(start.y)])
+        }
+        Ordering::Less => SegmentIntersection::Overlap(*start, *end),
+    }
+}
+
+/// Smallest convex polygon containing every point, counter-clockwise from the lowest x,
+/// without collinear points on its edges (Andrew's monotone chain)
+#[allow(dead_code)]
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by_key(|p| (p.x, p.y));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Point> = Vec::with_capacity(sorted.len() * 2);
+    for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let base = hull.len();
+
+        for point in pass {
+            while hull.len() >= base + 2
+                && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], &point) <= 0
+            {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+
+        hull.pop();
+    }
+
+    hull
+}
+
+/// Two points with the smallest euclidean distance, found with a sweep over x
+#[allow(dead_code)]
+pub fn closest_pair(points: &[Point]) -> Option<(Point, Point)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by_key(|p| (p.x, p.y));
+
+    let squared = |a: &Point, b: &Point| {
+        let (dx, dy) = ((a.x - b.x) as i128, (a.y - b.y) as i128);
+
+        dx * dx + dy * dy
+    };
+
+    let mut best: Option<(i128, Point, Point)> = None;
+    let mut active: BTreeSet<(isize, isize)> = BTreeSet::new();
+    let mut left = 0;
+
+    for point in &sorted {
+        if let Some((distance, _, _)) = best {
+            let reach = distance.isqrt() as isize + 1;
+
+            while point.x - sorted[left].x > reach {
+                active.remove(&(sorted[left].y, sorted[left].x));
+                left += 1;
+            }
+
+            let candidates =
+                active.range((point.y - reach, isize::MIN)..=(point.y + reach, isize::MAX));
+            for &(y, x) in candidates {
+                let other = Point::new(x, y);
+                let distance = squared(point, &other);
+
+                if best.is_none_or(|(d, _, _)| distance < d) {
+                    best = Some((distance, other, *point));
+                }
+            }
+        } else if let Some(&(y, x)) = active.iter().next() {
+            let other = Point::new(x, y);
+            best = Some((squared(point, &other), other, *point));
+        }
+
+        active.insert((point.y, point.x));
+    }
+
+    best.map(|(_, a, b)| (a, b))
+}
+
+/// Whether the point is inside or on the boundary of any simple polygon given by its corners
+#[allow(dead_code)]
+pub fn is_inside_polygon(polygon: &[Point], point: &Point) -> bool {
+    let edges = || {
+        polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .take(polygon.len())
+    };
+
+    if edges().any(|(a, b)| is_on_segment(point, a, b)) {
+        return true;
+    }
+
+    let winding: isize = edges()
+        .map(|(a, b)| match (a.y <= point.y, b.y <= point.y) {
+            (true, false) if cross(a, b, point) > 0 => 1,
+            (false, true) if cross(a, b, point) < 0 => -1,
+            _ => 0,
+        })
+        .sum();
+
+    winding != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::geometry::{
+        closest_pair, convex_hull, is_inside_polygon, orientation, segment_intersection,
+        Orientation, SegmentIntersection,
+    };
+    use crate::utils::point::Point;
+    use crate::utils::rational::Rational;
+
+    fn p(x: isize, y: isize) -> Point {
+        Point::new(x, y)
+    }
+
+    #[test]
+    fn orientation_of_turns() {
+        assert_eq!(
+            Orientation::CounterClockwise,
+            orientation(&p(0, 0), &p(4, 0), &p(4, 3))
+        );
+        assert_eq!(
+            Orientation::Clockwise,
+            orientation(&p(0, 0), &p(4, 0), &p(4, -3))
+        );
+        assert_eq!(
+            Orientation::Collinear,
+            orientation(&p(0, 0), &p(2, 2), &p(-5, -5))
+        );
+    }
+
+    #[test]
+    fn crossing_segments() {
+        assert_eq!(
+            SegmentIntersection::Point([Rational::new(3, 2), Rational::new(3, 2)]),
+            segment_intersection((&p(0, 0), &p(3, 3)), (&p(0, 3), &p(3, 0)))
+        );
+        assert_eq!(
+            SegmentIntersection::Point([Rational::from(3isize), Rational::from(0isize)]),
+            segment_intersection((&p(0, 0), &p(3, 0)), (&p(3, 0), &p(3, 5)))
+        );
+        assert_eq!(
+            SegmentIntersection::Disjoint,
+            segment_intersection((&p(0, 0), &p(3, 3)), (&p(5, 0), &p(4, 1)))
+        );
+        assert_eq!(
+            SegmentIntersection::Disjoint,
+            segment_intersection((&p(0, 0), &p(3, 3)), (&p(0, 1), &p(3, 4)))
+        );
+    }
+
+    #[test]
+    fn collinear_segments() {
+        assert_eq!(
+            SegmentIntersection::Overlap(p(2, 2), p(4, 4)),
+            segment_intersection((&p(0, 0), &p(4, 4)), (&p(6, 6), &p(2, 2)))
+        );
+        assert_eq!(
+            SegmentIntersection::Overlap(p(0, 1), p(0, 3)),
+            segment_intersection((&p(0, 5), &p(0, 1)), (&p(0, 0), &p(0, 3)))
+        );
+        assert_eq!(
+            SegmentIntersection::Point([Rational::from(4isize), Rational::from(4isize)]),
+            segment_intersection((&p(0, 0), &p(4, 4)), (&p(4, 4), &p(6, 6)))
+        );
+        assert_eq!(
+            SegmentIntersection::Disjoint,
+            segment_intersection((&p(0, 0), &p(1, 1)), (&p(2, 2), &p(6, 6)))
+        );
+    }
+
+    #[test]
+    fn hull() {
+        let points = [
+            p(0, 0),
+            p(2, 1),
+            p(4, 0),
+            p(4, 4),
+            p(2, 2),
+            p(0, 4),
+            p(2, 4),
+            p(0, 0),
+            p(1, 3),
+        ];
+
+        assert_eq!(
+            vec![p(0, 0), p(4, 0), p(4, 4), p(0, 4)],
+            convex_hull(&points)
+        );
+        assert_eq!(vec![p(0, 0), p(3, 3)], convex_hull(&[p(3, 3), p(0, 0)]));
+        assert_eq!(
+            vec![p(0, 0), p(3, 3)],
+            convex_hull(&[p(0, 0), p(1, 1), p(2, 2), p(3, 3)])
+        );
+    }
+
+    #[test]
+    fn closest() {
+        let points = [p(2, 3), p(12, 30), p(40, 50), p(5, 1), p(12, 10), p(3, 4)];
+
+        assert_eq!(Some((p(2, 3), p(3, 4))), closest_pair(&points));
+        assert_eq!(None, closest_pair(&[p(1, 1)]));
+
+        let far = [
+            p(-1_000_000_000, 0),
+            p(1_000_000_000, 0),
+            p(0, 7),
+            p(0, 1_000),
+        ];
+        assert_eq!(Some((p(0, 7), p(0, 1_000))), closest_pair(&far));
+    }
+
+    #[test]
+    fn inside_general_polygon() {
+        let triangle = [p(0, 0), p(10, 0), p(0, 10)];
+
+        assert!(is_inside_polygon(&triangle, &p(2, 2)));
+        assert!(is_inside_polygon(&triangle, &p(5, 5)));
+        assert!(is_inside_polygon(&triangle, &p(0, 7)));
+        assert!(!is_inside_polygon(&triangle, &p(6, 6)));
+        assert!(!is_inside_polygon(&triangle, &p(-1, 2)));
+
+        let arrow = [p(0, 0), p(6, 3), p(0, 6), p(3, 3)];
+        assert!(is_inside_polygon(&arrow, &p(4, 3)));
+        assert!(!is_inside_polygon(&arrow, &p(1, 3)));
+    }
+}
@@ -1,17 +1,24 @@
-use crate::utils::direction::Direction;
-use crate::utils::filled_region::FilledRegion;
+use crate::utils::grid_like::{GridLike, GridView};
+use crate::utils::grid_transform::GridTransform;
 use crate::utils::point::Point;
 use crate::utils::range::Range;
 use crate::utils::surface_range::SurfaceRange;
 use itertools::Itertools;
-use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
+use std::iter::{Enumerate, Zip};
+use std::sync::OnceLock;
 
+/// Grid stored as a contiguous row-major `Vec` covering the bounding box of all cells.
+/// Top left corner of the box may be any point, so negative coordinates are allowed.
+/// Cells that were never set inside the bounding box are kept as `None`.
+/// Points of the cells are only built when the grid is first iterated.
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
-    cells: HashMap<Point, T>,
+    cells: Vec<Option<T>>,
+    points: OnceLock<Vec<Point>>,
     columns_range: Range,
     rows_range: Range,
 }
@@ -21,45 +28,24 @@ where
     T: PartialEq,
 {
     pub fn new(cells: HashMap<Point, T>) -> Self {
-        let columns_range = Self::calculate_columns_range(&cells);
-        let rows_range = Self::calculate_rows_range(&cells);
-
-        Self {
-            cells,
-            columns_range,
-            rows_range,
-        }
+        Self::from_points(cells)
     }
 
     pub fn from_custom(input: &str, func: fn(char) -> T) -> Self {
-        let cells: HashMap<Point, T> = input
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| -> Vec<(Point, T)> {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, c)| (Point::new(x as isize, y as isize), func(c)))
-                    .collect()
-            })
-            .collect();
-
-        Self::new(cells)
+        Self::from_points(input.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(move |(x, c)| (Point::new(x as isize, y as isize), func(c)))
+        }))
     }
 
     pub fn from_custom_as_bytes(input: &str, func: fn(&u8) -> T) -> Self {
-        let cells: HashMap<Point, T> = input
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| -> Vec<(Point, T)> {
-                line.as_bytes()
-                    .iter()
-                    .enumerate()
-                    .map(|(x, c)| (Point::new(x as isize, y as isize), func(c)))
-                    .collect()
-            })
-            .collect();
-
-        Self::new(cells)
+        Self::from_points(input.lines().enumerate().flat_map(|(y, line)| {
+            line.as_bytes()
+                .iter()
+                .enumerate()
+                .map(move |(x, c)| (Point::new(x as isize, y as isize), func(c)))
+        }))
     }
 
     #[allow(dead_code)]
@@ -67,23 +53,92 @@ where
     where
         T: Clone,
     {
-        let mut cells: HashMap<Point, T> = HashMap::with_capacity(surface_range.area());
+        let cells = vec![Some(element); surface_range.area()];
 
-        for x in surface_range.columns().iter() {
-            for y in surface_range.rows().iter() {
-                cells.insert(Point::new(x, y), element.clone());
-            }
+        Self::with_ranges(cells, surface_range.columns(), surface_range.rows())
+    }
+
+    /// Cells in the bounding box are allocated up front, so far apart points are rejected
+    fn from_points<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Point, T)>,
+    {
+        let cells: Vec<(Point, T)> = iter.into_iter().collect();
+
+        let columns_range = Range::new(
+            cells.iter().map(|(p, _)| p.x).min().unwrap(),
+            cells.iter().map(|(p, _)| p.x).max().unwrap(),
+        )
+        .unwrap();
+        let rows_range = Range::new(
+            cells.iter().map(|(p, _)| p.y).min().unwrap(),
+            cells.iter().map(|(p, _)| p.y).max().unwrap(),
+        )
+        .unwrap();
+
+        let area = (columns_range.len() * rows_range.len()) as usize;
+        assert!(
+            area <= MAX_AREA,
+            "Bounding box of {}x{} cells is too large for a grid",
+            columns_range.len(),
+            rows_range.len()
+        );
+        let mut grid =
+            Self::with_ranges((0..area).map(|_| None).collect(), columns_range, rows_range);
+
+        for (point, element) in cells {
+            let index = grid.index(&point).unwrap();
+            grid.cells[index] = Some(element);
         }
 
-        Self::new(cells)
+        grid
+    }
+
+    fn with_ranges(cells: Vec<Option<T>>, columns_range: Range, rows_range: Range) -> Self {
+        Self {
+            cells,
+            points: OnceLock::new(),
+            columns_range,
+            rows_range,
+        }
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.columns_range.len() as usize
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.rows_range.len() as usize
+    }
+
+    #[inline]
+    fn index(&self, point: &Point) -> Option<usize> {
+        if !self.is_in(point) {
+            return None;
+        }
+
+        let x = (point.x - self.columns_range.start()) as usize;
+        let y = (point.y - self.rows_range.start()) as usize;
+
+        Some(y * self.width() + x)
+    }
+
+    fn points(&self) -> &[Point] {
+        self.points.get_or_init(|| {
+            (0..self.cells.len())
+                .map(|i| point_at(i, self.columns_range, self.rows_range))
+                .collect()
+        })
     }
 
     pub fn get(&self, x: isize, y: isize) -> Option<&T> {
-        self.cells.get(&Point::new(x, y))
+        self.get_for_point(&Point::new(x, y))
     }
 
     pub fn get_for_point(&self, point: &Point) -> Option<&T> {
-        self.cells.get(point)
+        self.index(point).and_then(|i| self.cells[i].as_ref())
     }
 
     pub fn is_for_point(&self, point: &Point, element: T) -> bool {
@@ -91,18 +146,12 @@ where
     }
 
     pub fn get_first_position(&self, element: &T) -> Option<Point> {
-        self.cells.iter().find_map(|(p, e)| {
-            if element == e {
-                return Some(*p);
-            }
-
-            None
-        })
+        self.iter()
+            .find_map(|(p, e)| if element == e { Some(*p) } else { None })
     }
 
     pub fn get_all_positions(&self, element: &T) -> Vec<Point> {
-        self.cells
-            .iter()
+        self.iter()
             .filter(|(_, e)| element == *e)
             .map(|(p, _)| *p)
             .collect()
@@ -113,13 +162,15 @@ where
     }
 
     pub fn rows(&self) -> BTreeMap<isize, BTreeMap<&Point, &T>> {
+        let width = self.width();
+        let points = self.points();
+
         self.rows_range
             .iter()
-            .map(|y| {
-                let cells_in_row = self
-                    .cells
-                    .iter()
-                    .filter(|(&point, _)| point.y == y)
+            .enumerate()
+            .map(|(i, y)| {
+                let cells_in_row = (i * width..(i + 1) * width)
+                    .filter_map(|j| self.cells[j].as_ref().map(|e| (&points[j], e)))
                     .collect();
 
                 (y, cells_in_row)
@@ -128,13 +179,16 @@ where
     }
 
     pub fn columns(&self) -> BTreeMap<isize, BTreeMap<&Point, &T>> {
+        let width = self.width();
+        let points = self.points();
+
         self.columns_range
             .iter()
-            .map(|x| {
-                let cells_in_column = self
-                    .cells
-                    .iter()
-                    .filter(|(&point, _)| point.x == x)
+            .enumerate()
+            .map(|(i, x)| {
+                let cells_in_column = (i..self.cells.len())
+                    .step_by(width)
+                    .filter_map(|j| self.cells[j].as_ref().map(|e| (&points[j], e)))
                     .collect();
 
                 (x, cells_in_column)
@@ -151,13 +205,24 @@ where
         }
     }
 
+    /// Inserts a new row at the given position and moves rows below it to the south
     pub fn insert_row(&mut self, row: isize, element: T)
     where
         T: Clone,
     {
-        self.move_rows_to_south_from(row);
-        self.add_row(row, element);
-        self.recalculate_ranges();
+        let rows_range = Range::new(self.rows_range.start(), self.rows_range.end() + 1).unwrap();
+        assert!(rows_range.contains(row), "Row {} is out of the grid", row);
+
+        let at = (row - self.rows_range.start()) as usize * self.width();
+        let width = self.width();
+        self.cells
+            .splice(at..at, (0..width).map(|_| Some(element.clone())));
+
+        *self = Self::with_ranges(
+            std::mem::take(&mut self.cells),
+            self.columns_range,
+            rows_range,
+        );
     }
 
     pub fn insert_columns(&mut self, columns: Vec<isize>, element: T)
@@ -169,13 +234,31 @@ where
         }
     }
 
+    /// Inserts a new column at the given position and moves columns on the right of it to the east
     pub fn insert_column(&mut self, column: isize, element: T)
     where
         T: Clone,
     {
-        self.move_columns_to_east_from(column);
-        self.add_column(column, element);
-        self.recalculate_ranges();
+        let columns_range =
+            Range::new(self.columns_range.start(), self.columns_range.end() + 1).unwrap();
+        assert!(
+            columns_range.contains(column),
+            "Column {} is out of the grid",
+            column
+        );
+
+        let at = (column - self.columns_range.start()) as usize;
+        let width = self.width();
+        let mut cells: Vec<Option<T>> = Vec::with_capacity(self.cells.len() + self.height());
+        let mut old_cells = std::mem::take(&mut self.cells).into_iter();
+
+        for _ in self.rows_range.iter() {
+            cells.extend(old_cells.by_ref().take(at));
+            cells.push(Some(element.clone()));
+            cells.extend(old_cells.by_ref().take(width - at));
+        }
+
+        *self = Self::with_ranges(cells, columns_range, self.rows_range);
     }
 
     pub fn rows_range(&self) -> Range {
@@ -190,8 +273,12 @@ where
         SurfaceRange::new(self.columns_range(), self.rows_range())
     }
 
+    fn get_mut_for_point(&mut self, point: &Point) -> Option<&mut T> {
+        self.index(point).and_then(|i| self.cells[i].as_mut())
+    }
+
     pub fn modify(&mut self, point: Point, new_value: T) {
-        *self.cells.get_mut(&point).unwrap() = new_value;
+        *self.get_mut_for_point(&point).unwrap() = new_value;
     }
 
     #[allow(dead_code)]
@@ -209,107 +296,20 @@ where
         F: FnMut(&mut T),
     {
         for point in points {
-            self.cells.entry(point).and_modify(&mut func);
-        }
-    }
-
-    fn move_rows_to_south_from(&mut self, from: isize) {
-        for y in self
-            .rows_range
-            .iter()
-            .skip(from as usize)
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-        {
-            for x in self.columns_range.iter() {
-                let old = Point::new(x, y);
-                let new = old.move_in(Direction::South);
-
-                self.replace_position(&old, new);
-            }
-        }
-    }
-
-    fn replace_position(&mut self, old: &Point, new: Point) {
-        if let Some(v) = self.cells.remove(old) {
-            self.cells.insert(new, v);
-        }
-    }
-
-    fn move_columns_to_east_from(&mut self, from: isize) {
-        for x in self
-            .columns_range
-            .iter()
-            .skip(from as usize)
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-        {
-            for y in self.rows_range.iter() {
-                let old = Point::new(x, y);
-                let new = old.move_in(Direction::East);
-
-                self.replace_position(&old, new);
+            if let Some(element) = self.get_mut_for_point(&point) {
+                func(element);
             }
         }
     }
 
-    fn add_row(&mut self, row: isize, element: T)
-    where
-        T: Clone,
-    {
-        for x in self.columns_range.iter() {
-            let new = Point::new(x, row);
-
-            self.cells.insert(new, element.clone());
-        }
-    }
-
-    fn add_column(&mut self, column: isize, element: T)
-    where
-        T: Clone,
-    {
-        for y in self.rows_range.iter() {
-            let new = Point::new(column, y);
-
-            self.cells.insert(new, element.clone());
-        }
-    }
-
-    fn calculate_rows_range(cells: &HashMap<Point, T>) -> Range {
-        let y: Vec<isize> = cells.keys().map(|k| k.y).collect();
-
-        Range::new(*y.iter().min().unwrap(), *y.iter().max().unwrap()).unwrap()
-    }
-
-    fn calculate_columns_range(cells: &HashMap<Point, T>) -> Range {
-        let x: Vec<isize> = cells.keys().map(|k| k.x).collect();
-
-        Range::new(*x.iter().min().unwrap(), *x.iter().max().unwrap()).unwrap()
-    }
-
-    fn recalculate_ranges(&mut self) {
-        self.columns_range = Self::calculate_columns_range(&self.cells);
-        self.rows_range = Self::calculate_rows_range(&self.cells)
-    }
-
     pub fn elements_with_points(&self) -> HashMap<T, Vec<Point>>
     where
         T: Eq + Hash + Clone,
     {
         let mut elements: HashMap<T, Vec<Point>> = HashMap::new();
-        let surface_range = self.surface();
-
-        for x in surface_range.columns().iter() {
-            for y in surface_range.rows().iter() {
-                let element = self.get(x, y).unwrap();
 
-                elements
-                    .entry(element.clone())
-                    .or_default()
-                    .push(Point::new(x, y));
-            }
+        for (point, element) in self.iter() {
+            elements.entry(element.clone()).or_default().push(*point);
         }
 
         elements
@@ -319,58 +319,12 @@ where
     where
         F: Fn(&Point, &T) -> bool,
     {
-        self.cells
-            .iter()
+        self.iter()
             .find(|(point, element)| find_func(point, element))
     }
 
-    pub fn get_all_regions(&self) -> Vec<FilledRegion> {
-        let surface = self.surface();
-        let mut visited: HashSet<Point> = HashSet::new();
-
-        let mut regions: Vec<FilledRegion> = Vec::new();
-
-        while visited.len() != surface.area() {
-            let not_visited_func = |point: &Point, _element: &T| !visited.contains(point);
-            let not_visited = self.find(&not_visited_func).unwrap();
-
-            let region = self.extract_region(not_visited.0);
-
-            visited.extend(region.points());
-            regions.push(region);
-        }
-
-        regions
-    }
-
-    /// It is flood fill algorithm implementation
-    fn extract_region(&self, starting_point: &Point) -> FilledRegion {
-        let element = self.get_for_point(starting_point).unwrap();
-        let mut visited: HashSet<Point> = HashSet::from_iter(vec![*starting_point]);
-        let mut queue = VecDeque::from(vec![*starting_point]);
-
-        while let Some(point) = queue.pop_front() {
-            let adjacent = point.adjacent();
-            let neighbours_with_the_same_element = adjacent
-                .iter()
-                .filter(|p| {
-                    self.get_for_point(p).is_some_and(|e| e == element) && !visited.contains(p)
-                })
-                .collect_vec();
-
-            visited.extend(neighbours_with_the_same_element.clone());
-
-            for neighbour in neighbours_with_the_same_element {
-                queue.push_back(*neighbour);
-            }
-        }
-
-        FilledRegion::try_from(visited).unwrap()
-    }
-
     pub fn _elements_in_surface(&self, element: T, surface: SurfaceRange) -> Vec<Point> {
-        self.cells
-            .iter()
+        self.iter()
             .filter_map(|(point, e)| {
                 if e == &element && surface.contains(*point) {
                     Some(*point)
@@ -399,31 +353,80 @@ where
     where
         T: Clone,
     {
-        self.cells.clone()
+        self.iter().map(|(p, e)| (*p, e.clone())).collect()
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (&Point, &T)> {
-        self.cells.iter()
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
     }
 }
 
 impl<T> Display for Grid<T>
 where
-    T: Display + Ord,
+    T: Display + PartialEq,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut printed_grid = String::new();
-        for y in self.rows_range.iter() {
-            for x in self.columns_range.iter() {
-                let el = self.get(x, y).unwrap();
+        self.fmt_cells(f)
+    }
+}
 
-                printed_grid += el.to_string().as_str()
-            }
+impl<T> GridView for Grid<T>
+where
+    T: PartialEq,
+{
+    type Cell = T;
 
-            printed_grid += "\n";
+    fn get_for_point(&self, point: &Point) -> Option<&T> {
+        Grid::get_for_point(self, point)
+    }
+}
+
+impl<T> GridLike for Grid<T>
+where
+    T: PartialEq,
+{
+    fn surface(&self) -> Option<SurfaceRange> {
+        Some(Grid::surface(self))
+    }
+
+    fn set_for_point(&mut self, point: &Point, value: T) {
+        if let Some(i) = self.index(point) {
+            self.cells[i] = Some(value);
         }
+    }
 
-        write!(f, "{}", printed_grid)
+    fn parse_with<F>(input: &str, mut func: F) -> Self
+    where
+        F: FnMut(char) -> T,
+    {
+        let mut cells: Vec<(Point, T)> = Vec::with_capacity(input.len());
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                cells.push((Point::new(x as isize, y as isize), func(c)));
+            }
+        }
+
+        Self::from_points(cells)
+    }
+}
+
+impl<T> GridTransform for Grid<T>
+where
+    T: Clone + PartialEq,
+{
+    fn remap<F>(&self, surface: SurfaceRange, source: F) -> Self
+    where
+        F: Fn(Point) -> Point,
+    {
+        let cells = surface
+            .rows()
+            .iter()
+            .flat_map(|y| surface.columns().iter().map(move |x| Point::new(x, y)))
+            .map(|p| Grid::get_for_point(self, &source(p)).cloned())
+            .collect();
+
+        Self::with_ranges(cells, surface.columns(), surface.rows())
     }
 }
 
@@ -432,18 +435,11 @@ where
     T: From<char> + PartialEq,
 {
     fn from(value: &str) -> Self {
-        let cells: HashMap<Point, T> = value
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| -> Vec<(Point, T)> {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, c)| (Point::new(x as isize, y as isize), T::from(c)))
-                    .collect()
-            })
-            .collect();
-
-        Grid::new(cells)
+        Grid::from_points(value.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(move |(x, c)| (Point::new(x as isize, y as isize), T::from(c)))
+        }))
     }
 }
 
@@ -464,15 +460,65 @@ where
     }
 }
 
+/// Largest bounding box a grid allocates, about 64M cells
+const MAX_AREA: usize = 1 << 26;
+
+/// Point of the cell stored at the index of a row-major vec covering the ranges
+#[inline]
+fn point_at(index: usize, columns_range: Range, rows_range: Range) -> Point {
+    let width = columns_range.len() as usize;
+
+    Point::new(
+        columns_range.start() + (index % width) as isize,
+        rows_range.start() + (index / width) as isize,
+    )
+}
+
+/// Existing cells of a grid in row-major order, with their points
+pub struct IntoIter<T> {
+    cells: Enumerate<std::vec::IntoIter<Option<T>>>,
+    columns_range: Range,
+    rows_range: Range,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = (Point, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (columns_range, rows_range) = (self.columns_range, self.rows_range);
+
+        self.cells
+            .find_map(|(i, cell)| cell.map(|c| (point_at(i, columns_range, rows_range), c)))
+    }
+}
+
+/// Existing cells of a grid in row-major order, with their points
+pub struct Iter<'a, T> {
+    cells: Zip<std::slice::Iter<'a, Point>, std::slice::Iter<'a, Option<T>>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (&'a Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells
+            .find_map(|(point, cell)| cell.as_ref().map(|c| (point, c)))
+    }
+}
+
 impl<T> IntoIterator for Grid<T>
 where
     T: PartialEq,
 {
     type Item = (Point, T);
-    type IntoIter = std::collections::hash_map::IntoIter<Point, T>;
+    type IntoIter = IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.cells.into_iter()
+        IntoIter {
+            cells: self.cells.into_iter().enumerate(),
+            columns_range: self.columns_range,
+            rows_range: self.rows_range,
+        }
     }
 }
 
@@ -481,10 +527,12 @@ where
     T: PartialEq,
 {
     type Item = (&'a Point, &'a T);
-    type IntoIter = std::collections::hash_map::Iter<'a, Point, T>;
+    type IntoIter = Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.cells.iter()
+        Iter {
+            cells: self.points().iter().zip(self.cells.iter()),
+        }
     }
 }
 
@@ -493,8 +541,7 @@ where
     T: PartialEq,
 {
     fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
-        let cells: HashMap<Point, T> = iter.into_iter().collect();
-        Grid::new(cells)
+        Grid::from_points(iter)
     }
 }
 
@@ -613,6 +660,57 @@ mod tests {
         assert_eq!("..\nAB\n..\nCD\n..\n", grid.to_string());
     }
 
+    #[test]
+    fn negative_coordinates() {
+        let grid: Grid<char> = Grid::from_iter(vec![
+            (Point::new(-2, -1), 'A'),
+            (Point::new(-1, -1), 'B'),
+            (Point::new(-2, 0), 'C'),
+            (Point::new(-1, 0), 'D'),
+        ]);
+
+        assert_eq!(Some(&'A'), grid.get(-2, -1));
+        assert_eq!(Some(&'D'), grid.get(-1, 0));
+        assert!(grid.get(0, 0).is_none());
+        assert_eq!("AB\nCD\n", grid.to_string());
+
+        let mut grid = grid;
+        grid.insert_row(0, '.');
+        grid.insert_column(-1, '.');
+
+        assert_eq!("A.B\n...\nC.D\n", grid.to_string());
+        assert_eq!(Some(&'D'), grid.get(0, 1));
+    }
+
+    #[test]
+    fn sparse_grid() {
+        let mut hash_map: HashMap<Point, char> = HashMap::new();
+        hash_map.insert(Point::new(0, 0), 'A');
+        hash_map.insert(Point::new(2, 2), 'B');
+
+        let grid = Grid::new(hash_map);
+
+        assert!(grid.get(1, 1).is_none());
+        assert_eq!(2, grid.iter().count());
+        assert_eq!(1, grid.rows().get(&2).unwrap().len());
+        assert_eq!(0, grid.columns().get(&1).unwrap().len());
+        assert_eq!(
+            HashMap::from([('A', vec![Point::new(0, 0)]), ('B', vec![Point::new(2, 2)])]),
+            grid.elements_with_points()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Bounding box of 2000000001x1 cells is too large for a grid")]
+    fn far_apart_points() {
+        let _: Grid<char> = [
+            (Point::new(-1_000_000_000, 0), 'A'),
+            (Point::new(1_000_000_000, 0), 'B'),
+        ]
+        .into_iter()
+        .collect();
+    }
+
     #[test]
     fn elements_with_points() {
         const GRID: &str = r#".....
@@ -0,0 +1,231 @@
+use crate::utils::direction::Direction;
+use crate::utils::filled_region::FilledRegion;
+use crate::utils::grid_search::{GridSearch, Neighbourhood};
+use crate::utils::point::Point;
+use crate::utils::surface_range::SurfaceRange;
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Display;
+
+/// Read-only access to cells addressed by `Point`, implemented by grid storages and by views over them.
+/// Neighbours are the points that searches are allowed to step into.
+pub trait GridView {
+    type Cell;
+
+    fn get_for_point(&self, point: &Point) -> Option<&Self::Cell>;
+
+    /// Directions used by searches over the view
+    fn neighbourhood(&self) -> Neighbourhood {
+        Neighbourhood::Orthogonal
+    }
+
+    /// Adjacent point in the given direction if it is inside the grid
+    fn step(&self, point: &Point, direction: Direction) -> Option<Point> {
+        let next = point.move_in(direction);
+
+        self.get_for_point(&next).map(|_| next)
+    }
+
+    /// Adjacent points in four main directions which are inside the grid
+    #[allow(dead_code)]
+    fn neighbours(&self, point: &Point) -> impl Iterator<Item = Point> {
+        Neighbourhood::Orthogonal
+            .directions()
+            .iter()
+            .filter_map(|direction| self.step(point, *direction))
+    }
+
+    /// Adjacent points in all eight directions which are inside the grid
+    #[allow(dead_code)]
+    fn neighbours_with_diagonals(&self, point: &Point) -> impl Iterator<Item = Point> {
+        Neighbourhood::WithDiagonals
+            .directions()
+            .iter()
+            .filter_map(|direction| self.step(point, *direction))
+    }
+
+    /// Points reachable from `start` over cells accepted by `passable`,
+    /// empty when the start itself is not passable
+    fn flood_fill<F>(&self, start: Point, passable: F) -> HashSet<Point>
+    where
+        F: Fn(&Point, &Self::Cell) -> bool,
+    {
+        if !self
+            .get_for_point(&start)
+            .is_some_and(|c| passable(&start, c))
+        {
+            return HashSet::new();
+        }
+
+        GridSearch::passable(self, &passable)
+            .distances([start])
+            .iter()
+            .map(|(point, _)| *point)
+            .collect()
+    }
+}
+
+/// Common interface of grid storages addressed by `Point`.
+/// Algorithms written against it work with `Grid` as well as `LightGrid`.
+pub trait GridLike: GridView {
+    /// Bounding box of all cells, None for a grid without cells as ranges cannot be empty
+    fn surface(&self) -> Option<SurfaceRange>;
+
+    /// Points outside the grid are ignored
+    fn set_for_point(&mut self, point: &Point, value: Self::Cell);
+
+    #[allow(dead_code)]
+    fn parse_with<F>(input: &str, func: F) -> Self
+    where
+        Self: Sized,
+        F: FnMut(char) -> Self::Cell;
+
+    #[allow(dead_code)]
+    fn width(&self) -> usize {
+        self.surface()
+            .map_or(0, |surface| surface.columns().len() as usize)
+    }
+
+    #[allow(dead_code)]
+    fn height(&self) -> usize {
+        self.surface()
+            .map_or(0, |surface| surface.rows().len() as usize)
+    }
+
+    /// All points of the bounding box in row-major order
+    fn points(&self) -> impl Iterator<Item = Point> {
+        self.surface().into_iter().flat_map(|surface| {
+            surface
+                .rows()
+                .iter()
+                .flat_map(move |y| surface.columns().iter().map(move |x| Point::new(x, y)))
+        })
+    }
+
+    /// All existing cells in row-major order
+    fn cells(&self) -> impl Iterator<Item = (Point, &Self::Cell)> {
+        self.points()
+            .filter_map(|point| self.get_for_point(&point).map(|cell| (point, cell)))
+    }
+
+    /// Splits the grid into contiguous regions of equal cells
+    fn get_all_regions(&self) -> Vec<FilledRegion>
+    where
+        Self::Cell: PartialEq,
+    {
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut regions: Vec<FilledRegion> = Vec::new();
+
+        for (point, element) in self.cells() {
+            if visited.contains(&point) {
+                continue;
+            }
+
+            let region = self.flood_fill(point, |_, e| e == element);
+
+            visited.extend(region.iter());
+            regions.push(FilledRegion::try_from(region).unwrap());
+        }
+
+        regions
+    }
+
+    /// Writes cells row by row, missing cells are printed as a space
+    fn fmt_cells(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        Self::Cell: Display,
+    {
+        let Some(surface) = self.surface() else {
+            return Ok(());
+        };
+
+        for y in surface.rows().iter() {
+            for x in surface.columns().iter() {
+                match self.get_for_point(&Point::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, " ")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::Grid;
+    use crate::utils::grid_like::{GridLike, GridView};
+    use crate::utils::light_grid::LightGrid;
+    use crate::utils::point::Point;
+
+    const EXAMPLE: &str = r#"AAB
+ABB
+CCB"#;
+
+    fn count_regions<G: GridLike<Cell = char>>(grid: &G) -> usize {
+        grid.get_all_regions().len()
+    }
+
+    #[test]
+    fn same_algorithm_for_both_storages() {
+        let grid: Grid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+        let light_grid: LightGrid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+
+        assert_eq!(3, count_regions(&grid));
+        assert_eq!(3, count_regions(&light_grid));
+    }
+
+    #[test]
+    fn dimensions() {
+        let light_grid: LightGrid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+
+        assert_eq!(3, light_grid.width());
+        assert_eq!(3, light_grid.height());
+        assert_eq!(9, light_grid.cells().count());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid: Grid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+
+        assert_eq!(2, grid.neighbours(&Point::new(0, 0)).count());
+        assert_eq!(4, grid.neighbours(&Point::new(1, 1)).count());
+        assert_eq!(3, grid.neighbours_with_diagonals(&Point::new(2, 2)).count());
+        assert_eq!(8, grid.neighbours_with_diagonals(&Point::new(1, 1)).count());
+    }
+
+    #[test]
+    fn set_for_point() {
+        let mut light_grid: LightGrid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+
+        light_grid.set_for_point(&Point::new(1, 2), 'X');
+        light_grid.set_for_point(&Point::new(-1, 0), 'X');
+
+        assert_eq!(Some(&'X'), light_grid.get_for_point(&Point::new(1, 2)));
+        assert_eq!("AAB\nABB\nCXB\n", light_grid.to_string());
+    }
+
+    #[test]
+    fn flood_fill() {
+        let grid: Grid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+
+        let region = grid.flood_fill(Point::new(2, 0), |_, &c| c == 'B');
+
+        assert_eq!(4, region.len());
+        assert!(grid
+            .flood_fill(Point::new(0, 0), |_, &c| c == 'B')
+            .is_empty());
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let grid: Grid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+        let light_grid: LightGrid<char> = GridLike::parse_with(EXAMPLE, |c| c);
+
+        assert_eq!(format!("{}\n", EXAMPLE), grid.to_string());
+        assert_eq!(format!("{}\n", EXAMPLE), light_grid.to_string());
+    }
+}
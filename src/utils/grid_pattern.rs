@@ -0,0 +1,349 @@
+use crate::utils::direction::Direction;
+use crate::utils::grid::Grid;
+use crate::utils::grid_like::{GridLike, GridView};
+use crate::utils::grid_search::Neighbourhood;
+use crate::utils::point::Point;
+use itertools::Itertools;
+
+/// Small shape searched for inside a bigger grid.
+/// Wildcard cells are not stored, they only count towards the size of the pattern.
+/// By default the whole pattern has to fit inside the grid, see `allow_overhang`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridPattern<T> {
+    cells: Vec<(Point, T)>,
+    width: isize,
+    height: isize,
+    overhang: bool,
+}
+
+impl<T> GridPattern<T>
+where
+    T: PartialEq + Clone,
+{
+    /// Pattern without wildcards, positions are moved so the top left cell is at the origin
+    #[allow(dead_code)]
+    pub fn new(cells: Vec<(Point, T)>) -> Self {
+        let min_x = cells.iter().map(|(p, _)| p.x).min().unwrap_or(0);
+        let min_y = cells.iter().map(|(p, _)| p.y).min().unwrap_or(0);
+        let max_x = cells.iter().map(|(p, _)| p.x).max().unwrap_or(-1);
+        let max_y = cells.iter().map(|(p, _)| p.y).max().unwrap_or(-1);
+
+        Self::with_size(
+            cells
+                .into_iter()
+                .map(|(p, cell)| (Point::new(p.x - min_x, p.y - min_y), cell))
+                .collect(),
+            max_x - min_x + 1,
+            max_y - min_y + 1,
+        )
+    }
+
+    fn with_size(cells: Vec<(Point, T)>, width: isize, height: isize) -> Self {
+        Self {
+            cells,
+            width,
+            height,
+            overhang: false,
+        }
+    }
+
+    /// Every pattern cell equal to `wildcard` matches anything.
+    /// Matches are reported at the top left corner of the whole grid, wildcards included.
+    pub fn from_grid(grid: &Grid<T>, wildcard: &T) -> Self {
+        let surface = grid.surface();
+        let origin = surface.top_left_corner();
+
+        Self::with_size(
+            grid.cells()
+                .filter(|(_, cell)| *cell != wildcard)
+                .map(|(p, cell)| (p - origin, cell.clone()))
+                .collect(),
+            surface.columns().len(),
+            surface.rows().len(),
+        )
+    }
+
+    /// Lets wildcard cells lie outside the grid, so the pattern can hang off its edges
+    #[allow(dead_code)]
+    pub fn allow_overhang(mut self) -> Self {
+        self.overhang = true;
+        self
+    }
+
+    /// Top left corners of all places in the grid where the pattern fits
+    pub fn find_in<G>(&self, grid: &G) -> Vec<Point>
+    where
+        G: GridLike<Cell = T>,
+    {
+        let origins: Vec<Point> = if self.overhang {
+            let (width, height) = (self.width, self.height);
+
+            // a pattern hanging off the top or left edge still has a stored cell inside
+            grid.points()
+                .flat_map(|p| {
+                    (0..height).flat_map(move |y| (0..width).map(move |x| p - Point::new(x, y)))
+                })
+                .sorted_by_key(|p| (p.y, p.x))
+                .dedup()
+                .collect()
+        } else {
+            grid.points().collect()
+        };
+
+        origins
+            .into_iter()
+            .filter(|origin| self.matches_at(grid, origin))
+            .collect()
+    }
+
+    /// Like `find_in`, but for all distinct rotations and reflections of the pattern.
+    /// Different variants matching at the same place are reported separately.
+    pub fn find_in_any_orientation<G>(&self, grid: &G) -> Vec<Point>
+    where
+        G: GridLike<Cell = T>,
+    {
+        self.variants()
+            .iter()
+            .flat_map(|variant| variant.find_in(grid))
+            .collect()
+    }
+
+    pub fn matches_at<G>(&self, grid: &G, origin: &Point) -> bool
+    where
+        G: GridLike<Cell = T>,
+    {
+        let bottom_right = *origin + Point::new(self.width - 1, self.height - 1);
+        let fits = || {
+            grid.surface()
+                .is_some_and(|surface| surface.contains(*origin) && surface.contains(bottom_right))
+        };
+
+        (self.overhang || fits())
+            && self
+                .cells
+                .iter()
+                .all(|(p, cell)| grid.get_for_point(&(*origin + *p)) == Some(cell))
+    }
+
+    /// All distinct rotations and reflections of the pattern
+    pub fn variants(&self) -> Vec<Self> {
+        let mut variants: Vec<Self> = Vec::new();
+
+        for reflection in [self.clone(), self.flipped()] {
+            let mut variant = reflection;
+
+            for _ in 0..4 {
+                let next = variant.rotated_cw();
+
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+
+                variant = next;
+            }
+        }
+
+        variants
+    }
+
+    fn rotated_cw(&self) -> Self {
+        self.remap(self.height, self.width, |p| {
+            Point::new(self.height - 1 - p.y, p.x)
+        })
+    }
+
+    fn flipped(&self) -> Self {
+        self.remap(self.width, self.height, |p| {
+            Point::new(self.width - 1 - p.x, p.y)
+        })
+    }
+
+    fn remap<F>(&self, width: isize, height: isize, func: F) -> Self
+    where
+        F: Fn(Point) -> Point,
+    {
+        let mut cells: Vec<(Point, T)> = self
+            .cells
+            .iter()
+            .map(|(p, cell)| (func(*p), cell.clone()))
+            .collect();
+        cells.sort_by_key(|(p, _)| (p.y, p.x));
+
+        Self {
+            cells,
+            width,
+            height,
+            overhang: self.overhang,
+        }
+    }
+}
+
+impl GridPattern<char> {
+    /// Pattern written like a grid input, cells equal to `wildcard` match anything
+    pub fn parse(input: &str, wildcard: char) -> Self {
+        Self::from_grid(&Grid::from(input), &wildcard)
+    }
+}
+
+/// Starts and directions of all occurrences of the word written in a straight line,
+/// in any of the eight directions. One letter words are reported once, facing East.
+pub fn find_word<G>(grid: &G, word: &[G::Cell]) -> Vec<(Point, Direction)>
+where
+    G: GridLike,
+    G::Cell: PartialEq,
+{
+    let Some(first) = word.first() else {
+        return Vec::new();
+    };
+    let directions = match word.len() {
+        1 => &[Direction::East],
+        _ => Neighbourhood::WithDiagonals.directions(),
+    };
+
+    grid.cells()
+        .filter(|(_, cell)| *cell == first)
+        .flat_map(|(start, _)| {
+            directions
+                .iter()
+                .filter(move |direction| word_at(grid, start, **direction, &word[1..]))
+                .map(move |direction| (start, *direction))
+        })
+        .collect()
+}
+
+fn word_at<G>(grid: &G, start: Point, direction: Direction, rest: &[G::Cell]) -> bool
+where
+    G: GridView,
+    G::Cell: PartialEq,
+{
+    let mut current = start;
+
+    rest.iter().all(|letter| {
+        current = current.move_in(direction);
+
+        grid.get_for_point(&current) == Some(letter)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::direction::Direction;
+    use crate::utils::grid::Grid;
+    use crate::utils::grid_pattern::{find_word, GridPattern};
+    use crate::utils::light_grid::LightGrid;
+    use crate::utils::point::Point;
+
+    const EXAMPLE: &str = r#"ABCA
+BABC
+CBAB
+ABCA"#;
+
+    #[test]
+    fn find_in() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let pattern = GridPattern::parse("AB\n.A", '.');
+
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)],
+            pattern.find_in(&grid)
+        );
+    }
+
+    #[test]
+    fn wildcards_count_towards_size() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let pattern = GridPattern::parse("A.\n..", '.');
+
+        assert_eq!(3, pattern.find_in(&grid).len());
+        assert_eq!(6, pattern.allow_overhang().find_in(&grid).len());
+    }
+
+    #[test]
+    fn matches_at_top_left_of_wildcards() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let pattern = GridPattern::parse("..\n.B", '.');
+
+        assert_eq!(
+            vec![
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(2, 1),
+                Point::new(0, 2)
+            ],
+            pattern.find_in(&grid)
+        );
+        assert_eq!(
+            vec![
+                Point::new(0, -1),
+                Point::new(-1, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(2, 1),
+                Point::new(0, 2)
+            ],
+            pattern.allow_overhang().find_in(&grid)
+        );
+    }
+
+    #[test]
+    fn find_in_light_grid() {
+        let grid: LightGrid<char> = EXAMPLE.parse().unwrap();
+        let pattern = GridPattern::parse("BC\nAB", '.');
+
+        assert_eq!(
+            vec![Point::new(1, 0), Point::new(2, 1)],
+            pattern.find_in(&grid)
+        );
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(8, GridPattern::parse("AB\nC.", '.').variants().len());
+        assert_eq!(4, GridPattern::parse("M.M\n.A.\nS.S", '.').variants().len());
+        assert_eq!(1, GridPattern::parse("AA\nAA", '.').variants().len());
+    }
+
+    #[test]
+    fn find_in_any_orientation() {
+        let pattern = GridPattern::parse("M.M\n.A.\nS.S", '.');
+        let grid: Grid<char> = Grid::from("M.S.M\n.A.A.\nM.S.M");
+
+        assert!(pattern.find_in(&grid).is_empty());
+        assert_eq!(
+            vec![Point::new(2, 0), Point::new(0, 0)],
+            pattern.find_in_any_orientation(&grid)
+        );
+    }
+
+    #[test]
+    fn word_search() {
+        let grid: Grid<char> = Grid::from("CAT\nA.A\nTAC");
+
+        let mut found = find_word(&grid, &['C', 'A', 'T']);
+        found.sort();
+
+        assert_eq!(
+            vec![
+                (Point::new(0, 0), Direction::East),
+                (Point::new(0, 0), Direction::South),
+                (Point::new(2, 2), Direction::North),
+                (Point::new(2, 2), Direction::West),
+            ],
+            found
+        );
+        assert!(find_word(&grid, &['C', 'A', 'T', 'S']).is_empty());
+    }
+
+    #[test]
+    fn one_letter_word() {
+        let grid: Grid<char> = Grid::from("CAT\nA.A\nTAC");
+
+        assert_eq!(
+            vec![
+                (Point::new(0, 0), Direction::East),
+                (Point::new(2, 2), Direction::East),
+            ],
+            find_word(&grid, &['C'])
+        );
+    }
+}
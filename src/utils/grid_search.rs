@@ -0,0 +1,336 @@
+use crate::utils::direction::Direction;
+use crate::utils::direction::Direction::{
+    East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West,
+};
+use crate::utils::grid_like::GridView;
+use crate::utils::point::Point;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Directions in which a search can move from a point
+#[derive(Debug, Clone, PartialEq)]
+pub enum Neighbourhood {
+    Orthogonal,
+    WithDiagonals,
+    Custom(Vec<Direction>),
+}
+
+impl Neighbourhood {
+    const ORTHOGONAL: [Direction; 4] = [West, East, North, South];
+    const WITH_DIAGONALS: [Direction; 8] = [
+        North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+    ];
+
+    pub fn directions(&self) -> &[Direction] {
+        match self {
+            Self::Orthogonal => &Self::ORTHOGONAL,
+            Self::WithDiagonals => &Self::WITH_DIAGONALS,
+            Self::Custom(directions) => directions,
+        }
+    }
+}
+
+type CanMove<'a> = Box<dyn Fn(&Point, &Point) -> bool + 'a>;
+
+/// Breadth-first search over any grid view.
+/// Moves are allowed to neighbours for which `can_move(from, to)` is true.
+pub struct GridSearch<'a, G: ?Sized> {
+    grid: &'a G,
+    can_move: CanMove<'a>,
+    neighbourhood: Neighbourhood,
+    max_distance: Option<usize>,
+}
+
+impl<'a, G> GridSearch<'a, G>
+where
+    G: GridView + ?Sized,
+{
+    /// Search moving in the neighbourhood of the grid view
+    pub fn new<F>(grid: &'a G, can_move: F) -> Self
+    where
+        F: Fn(&Point, &Point) -> bool + 'a,
+    {
+        Self {
+            grid,
+            can_move: Box::new(can_move),
+            neighbourhood: grid.neighbourhood(),
+            max_distance: None,
+        }
+    }
+
+    /// Search where every cell accepted by `passable` can be entered
+    pub fn passable<F>(grid: &'a G, passable: F) -> Self
+    where
+        F: Fn(&Point, &G::Cell) -> bool + 'a,
+    {
+        Self::new(grid, move |_, to| {
+            grid.get_for_point(to).is_some_and(|c| passable(to, c))
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn with_neighbourhood(mut self, neighbourhood: Neighbourhood) -> Self {
+        self.neighbourhood = neighbourhood;
+        self
+    }
+
+    /// Stops the search at given distance, required for infinite grids
+    pub fn with_max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = Some(max_distance);
+        self
+    }
+
+    pub fn distances<I>(&self, starts: I) -> DistanceMap
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut map = DistanceMap::default();
+        let mut queue: VecDeque<Point> = VecDeque::new();
+
+        for start in starts {
+            if map.distances.insert(start, 0).is_none() {
+                map.path_counts.insert(start, 1);
+                queue.push_back(start);
+            }
+        }
+
+        while let Some(point) = queue.pop_front() {
+            let distance = map.distances[&point];
+            if self.max_distance.is_some_and(|max| distance >= max) {
+                continue;
+            }
+
+            let paths = map.path_counts[&point];
+
+            for direction in self.neighbourhood.directions() {
+                let Some(next) = self.grid.step(&point, *direction) else {
+                    continue;
+                };
+
+                if !(self.can_move)(&point, &next) {
+                    continue;
+                }
+
+                match map.distances.get(&next) {
+                    None => {
+                        map.distances.insert(next, distance + 1);
+                        map.predecessors.insert(next, point);
+                        map.path_counts.insert(next, paths);
+                        queue.push_back(next);
+                    }
+                    Some(&d) if d == distance + 1 => {
+                        *map.path_counts.get_mut(&next).unwrap() += paths;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        map
+    }
+}
+
+/// Result of a breadth-first search: shortest distances from the closest start
+#[derive(Debug, Default)]
+pub struct DistanceMap {
+    distances: HashMap<Point, usize>,
+    predecessors: HashMap<Point, Point>,
+    path_counts: HashMap<Point, usize>,
+}
+
+impl DistanceMap {
+    pub fn distance(&self, point: &Point) -> Option<usize> {
+        self.distances.get(point).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, &usize)> {
+        self.distances.iter()
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Number of different shortest paths leading to the point
+    pub fn path_count(&self, point: &Point) -> usize {
+        self.path_counts.get(point).copied().unwrap_or(0)
+    }
+
+    /// One of the shortest paths, from the start to the given point inclusive
+    #[allow(dead_code)]
+    pub fn path_to(&self, point: &Point) -> Option<Vec<Point>> {
+        self.distances.get(point)?;
+
+        let mut path = vec![*point];
+        let mut current = *point;
+        while let Some(previous) = self.predecessors.get(&current) {
+            path.push(*previous);
+            current = *previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    #[allow(dead_code)]
+    pub fn reachable_within(&self, steps: usize) -> HashSet<Point> {
+        self.distances
+            .iter()
+            .filter(|(_, &d)| d <= steps)
+            .map(|(p, _)| *p)
+            .collect()
+    }
+
+    /// Points where a walk of exactly `steps` can end, assuming it can go back and forth.
+    /// It holds for orthogonal moves, where every cycle has even length.
+    #[allow(dead_code)]
+    pub fn reachable_in_exactly(&self, steps: usize) -> HashSet<Point> {
+        self.distances
+            .iter()
+            .filter(|(_, &d)| d <= steps && d % 2 == steps % 2)
+            .map(|(p, _)| *p)
+            .collect()
+    }
+
+    pub fn count_reachable_in_exactly(&self, steps: usize) -> usize {
+        self.distances
+            .values()
+            .filter(|&&d| d <= steps && d % 2 == steps % 2)
+            .count()
+    }
+
+    /// Number of points reachable in even and odd number of steps
+    #[allow(dead_code)]
+    pub fn parity_counts(&self) -> (usize, usize) {
+        let even = self.distances.values().filter(|&&d| d % 2 == 0).count();
+
+        (even, self.distances.len() - even)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::direction::Direction;
+    use crate::utils::grid::Grid;
+    use crate::utils::grid_search::{GridSearch, Neighbourhood};
+    use crate::utils::point::Point;
+    use crate::utils::wrapping_grid::ToroidalGrid;
+
+    const EXAMPLE: &str = r#"S..#
+.#..
+...#
+#..E"#;
+
+    fn grid() -> Grid<char> {
+        Grid::from(EXAMPLE)
+    }
+
+    #[test]
+    fn distances() {
+        let grid = grid();
+        let map = GridSearch::passable(&grid, |_, &c| c != '#').distances([Point::new(0, 0)]);
+
+        assert_eq!(Some(0), map.distance(&Point::new(0, 0)));
+        assert_eq!(Some(2), map.distance(&Point::new(2, 0)));
+        assert_eq!(Some(6), map.distance(&Point::new(3, 3)));
+        assert_eq!(None, map.distance(&Point::new(1, 1)));
+        assert_eq!(12, map.len());
+    }
+
+    #[test]
+    fn path_to() {
+        let grid = grid();
+        let map = GridSearch::passable(&grid, |_, &c| c != '#').distances([Point::new(0, 0)]);
+
+        let path = map.path_to(&Point::new(3, 3)).unwrap();
+
+        assert_eq!(7, path.len());
+        assert_eq!(Point::new(0, 0), path[0]);
+        assert_eq!(Point::new(3, 3), path[6]);
+        assert!(path
+            .windows(2)
+            .all(|pair| pair[0].manhattan_distance(&pair[1]) == 1));
+        assert_eq!(None, map.path_to(&Point::new(1, 1)));
+    }
+
+    #[test]
+    fn path_count() {
+        let grid: Grid<char> = Grid::from("...\n...\n...");
+        let map = GridSearch::passable(&grid, |_, _| true).distances([Point::new(0, 0)]);
+
+        assert_eq!(1, map.path_count(&Point::new(2, 0)));
+        assert_eq!(2, map.path_count(&Point::new(1, 1)));
+        assert_eq!(6, map.path_count(&Point::new(2, 2)));
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let grid = grid();
+        let map = GridSearch::passable(&grid, |_, &c| c != '#')
+            .distances([Point::new(0, 0), Point::new(3, 3)]);
+
+        assert_eq!(Some(3), map.distance(&Point::new(1, 2)));
+        assert_eq!(Some(1), map.distance(&Point::new(2, 3)));
+    }
+
+    #[test]
+    fn reachable() {
+        let grid = grid();
+        let map = GridSearch::passable(&grid, |_, &c| c != '#').distances([Point::new(0, 0)]);
+
+        assert_eq!(3, map.reachable_within(1).len());
+        assert_eq!(5, map.reachable_within(2).len());
+        assert_eq!(3, map.reachable_in_exactly(2).len());
+        assert_eq!(3, map.count_reachable_in_exactly(2));
+        assert_eq!((7, 5), map.parity_counts());
+    }
+
+    #[test]
+    fn max_distance() {
+        let grid = grid();
+        let map = GridSearch::passable(&grid, |_, &c| c != '#')
+            .with_max_distance(2)
+            .distances([Point::new(0, 0)]);
+
+        assert_eq!(5, map.len());
+    }
+
+    #[test]
+    fn neighbourhood() {
+        let grid = grid();
+
+        let diagonal = GridSearch::passable(&grid, |_, &c| c != '#')
+            .with_neighbourhood(Neighbourhood::WithDiagonals)
+            .distances([Point::new(0, 0)]);
+        assert_eq!(Some(4), diagonal.distance(&Point::new(3, 3)));
+
+        let only_east = GridSearch::passable(&grid, |_, &c| c != '#')
+            .with_neighbourhood(Neighbourhood::Custom(vec![Direction::East]))
+            .distances([Point::new(0, 0)]);
+        assert_eq!(3, only_east.len());
+    }
+
+    #[test]
+    fn directed_moves() {
+        let grid: Grid<u32> = Grid::from_custom("0123\n1256", |c| c.to_digit(10).unwrap());
+        let map = GridSearch::new(&grid, |from, to| {
+            grid.get_for_point(to).copied() == grid.get_for_point(from).map(|h| h + 1)
+        })
+        .distances([Point::new(0, 0)]);
+
+        assert_eq!(Some(3), map.distance(&Point::new(3, 0)));
+        assert_eq!(Some(2), map.distance(&Point::new(1, 1)));
+        assert_eq!(2, map.path_count(&Point::new(1, 1)));
+        assert_eq!(None, map.distance(&Point::new(2, 1)));
+    }
+
+    #[test]
+    fn toroidal() {
+        let grid = grid();
+        let torus = ToroidalGrid::new(&grid);
+        let map = GridSearch::passable(&torus, |_, &c| c != '#').distances([Point::new(0, 0)]);
+
+        assert_eq!(Some(2), map.distance(&Point::new(3, 1)));
+        assert_eq!(None, map.distance(&Point::new(1, 1)));
+    }
+}
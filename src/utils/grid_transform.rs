@@ -0,0 +1,285 @@
+use crate::utils::grid_like::GridLike;
+use crate::utils::point::Point;
+use crate::utils::range::Range;
+use crate::utils::surface_range::SurfaceRange;
+
+/// Rotations, reflections and other rearrangements of grid cells.
+/// Results keep the top left corner of the original grid, apart from `crop`
+/// which keeps original positions of the cells. Empty grids stay empty.
+#[allow(dead_code)]
+pub trait GridTransform: GridLike + Clone {
+    /// Builds a grid covering `surface` where every cell is copied from the `source` point
+    fn remap<F>(&self, surface: SurfaceRange, source: F) -> Self
+    where
+        F: Fn(Point) -> Point;
+
+    fn rotate_cw(&self) -> Self {
+        let Some((origin, width, height)) = dimensions(self) else {
+            return self.clone();
+        };
+
+        self.remap(surface_at(origin, height, width), |p| {
+            let (x, y) = relative(origin, p);
+            origin + Point::new(y, height - 1 - x)
+        })
+    }
+
+    fn rotate_ccw(&self) -> Self {
+        let Some((origin, width, height)) = dimensions(self) else {
+            return self.clone();
+        };
+
+        self.remap(surface_at(origin, height, width), |p| {
+            let (x, y) = relative(origin, p);
+            origin + Point::new(width - 1 - y, x)
+        })
+    }
+
+    /// Mirrors the grid along the vertical axis, so the left edge becomes the right one
+    fn flip_horizontal(&self) -> Self {
+        let Some((origin, width, height)) = dimensions(self) else {
+            return self.clone();
+        };
+
+        self.remap(surface_at(origin, width, height), |p| {
+            let (x, y) = relative(origin, p);
+            origin + Point::new(width - 1 - x, y)
+        })
+    }
+
+    /// Mirrors the grid along the horizontal axis, so the top edge becomes the bottom one
+    fn flip_vertical(&self) -> Self {
+        let Some((origin, width, height)) = dimensions(self) else {
+            return self.clone();
+        };
+
+        self.remap(surface_at(origin, width, height), |p| {
+            let (x, y) = relative(origin, p);
+            origin + Point::new(x, height - 1 - y)
+        })
+    }
+
+    /// Rows become columns
+    fn transpose(&self) -> Self {
+        let Some((origin, width, height)) = dimensions(self) else {
+            return self.clone();
+        };
+
+        self.remap(surface_at(origin, height, width), |p| {
+            let (x, y) = relative(origin, p);
+            origin + Point::new(y, x)
+        })
+    }
+
+    /// Keeps only cells inside `surface`, fails if it doesn't overlap the grid
+    fn crop(&self, surface: SurfaceRange) -> Result<Self, String> {
+        let grid_surface = self.surface().ok_or("Cannot crop an empty grid")?;
+        let cropped = SurfaceRange::new(
+            grid_surface.columns().intersect(&surface.columns())?,
+            grid_surface.rows().intersect(&surface.rows())?,
+        );
+
+        Ok(self.remap(cropped, |p| p))
+    }
+
+    /// Repeats the grid `nx` times to the east and `ny` times to the south, both at least once
+    fn tile(&self, nx: usize, ny: usize) -> Self {
+        assert!(
+            nx > 0 && ny > 0,
+            "Cannot tile a grid {} times horizontally and {} times vertically",
+            nx,
+            ny
+        );
+
+        let Some((origin, width, height)) = dimensions(self) else {
+            return self.clone();
+        };
+
+        self.remap(
+            surface_at(origin, width * nx as isize, height * ny as isize),
+            |p| {
+                let (x, y) = relative(origin, p);
+                origin + Point::new(x % width, y % height)
+            },
+        )
+    }
+
+    /// All eight rotations and reflections of the grid; symmetric grids yield duplicates
+    fn symmetries(&self) -> impl Iterator<Item = Self> {
+        let flipped = self.flip_horizontal();
+
+        [self.clone(), flipped].into_iter().flat_map(|grid| {
+            let once = grid.rotate_cw();
+            let twice = once.rotate_cw();
+            let three_times = twice.rotate_cw();
+
+            [grid, once, twice, three_times]
+        })
+    }
+}
+
+#[allow(dead_code)]
+fn dimensions<G: GridLike>(grid: &G) -> Option<(Point, isize, isize)> {
+    let surface = grid.surface()?;
+
+    Some((
+        surface.top_left_corner(),
+        surface.columns().len(),
+        surface.rows().len(),
+    ))
+}
+
+#[allow(dead_code)]
+fn surface_at(origin: Point, width: isize, height: isize) -> SurfaceRange {
+    SurfaceRange::new(
+        Range::with_length(origin.x, width).unwrap(),
+        Range::with_length(origin.y, height).unwrap(),
+    )
+}
+
+#[allow(dead_code)]
+fn relative(origin: Point, point: Point) -> (isize, isize) {
+    (point.x - origin.x, point.y - origin.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::Grid;
+    use crate::utils::grid_transform::GridTransform;
+    use crate::utils::light_grid::LightGrid;
+    use crate::utils::point::Point;
+    use crate::utils::range::Range;
+    use crate::utils::surface_range::SurfaceRange;
+    use itertools::Itertools;
+
+    const EXAMPLE: &str = r#"ABC
+DEF"#;
+
+    fn grid() -> Grid<char> {
+        Grid::from(EXAMPLE)
+    }
+
+    fn light_grid() -> LightGrid<char> {
+        EXAMPLE.parse().unwrap()
+    }
+
+    #[test]
+    fn rotate_cw() {
+        assert_eq!("DA\nEB\nFC\n", grid().rotate_cw().to_string());
+        assert_eq!("DA\nEB\nFC\n", light_grid().rotate_cw().to_string());
+    }
+
+    #[test]
+    fn rotate_ccw() {
+        assert_eq!("CF\nBE\nAD\n", grid().rotate_ccw().to_string());
+        assert_eq!("CF\nBE\nAD\n", light_grid().rotate_ccw().to_string());
+    }
+
+    #[test]
+    fn rotate_four_times_is_identity() {
+        let rotated = grid().rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+
+        assert_eq!(grid().to_string(), rotated.to_string());
+    }
+
+    #[test]
+    fn flip() {
+        assert_eq!("CBA\nFED\n", grid().flip_horizontal().to_string());
+        assert_eq!("DEF\nABC\n", grid().flip_vertical().to_string());
+        assert_eq!("CBA\nFED\n", light_grid().flip_horizontal().to_string());
+        assert_eq!("DEF\nABC\n", light_grid().flip_vertical().to_string());
+    }
+
+    #[test]
+    fn transpose() {
+        assert_eq!("AD\nBE\nCF\n", grid().transpose().to_string());
+        assert_eq!("AD\nBE\nCF\n", light_grid().transpose().to_string());
+    }
+
+    #[test]
+    fn keeps_top_left_corner() {
+        let grid: Grid<char> = grid()
+            .into_iter()
+            .map(|(p, c)| (p + Point::new(-5, 3), c))
+            .collect();
+
+        let rotated = grid.rotate_cw();
+
+        assert_eq!(Range::new(-5, -4).unwrap(), rotated.columns_range());
+        assert_eq!(Range::new(3, 5).unwrap(), rotated.rows_range());
+        assert_eq!(Some(&'D'), rotated.get(-5, 3));
+    }
+
+    #[test]
+    fn crop() {
+        let cropped = grid().crop(SurfaceRange::from_points(1, 5, 0, 0)).unwrap();
+
+        assert_eq!("BC\n", cropped.to_string());
+        assert_eq!(Some(&'B'), cropped.get(1, 0));
+        assert!(cropped.get(0, 0).is_none());
+
+        let cropped = light_grid()
+            .crop(SurfaceRange::from_points(1, 2, 1, 1))
+            .unwrap();
+        assert_eq!("EF\n", cropped.to_string());
+
+        assert!(grid().crop(SurfaceRange::from_points(5, 6, 0, 1)).is_err());
+    }
+
+    #[test]
+    fn empty_grid() {
+        let empty: LightGrid<char> = "".parse().unwrap();
+
+        assert_eq!("", empty.rotate_cw().to_string());
+        assert_eq!("", empty.tile(2, 3).to_string());
+        assert_eq!(8, empty.symmetries().count());
+        assert_eq!(
+            Err("Cannot crop an empty grid".to_string()),
+            empty
+                .crop(SurfaceRange::square(1))
+                .map(|grid| grid.to_string())
+        );
+    }
+
+    #[test]
+    fn tile() {
+        assert_eq!(
+            "ABCABC\nDEFDEF\nABCABC\nDEFDEF\n",
+            grid().tile(2, 2).to_string()
+        );
+        assert_eq!(
+            "ABCABCABC\nDEFDEFDEF\n",
+            light_grid().tile(3, 1).to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot tile a grid 0 times horizontally and 2 times vertically")]
+    fn tile_zero_times() {
+        grid().tile(0, 2);
+    }
+
+    #[test]
+    fn symmetries() {
+        let symmetries = grid().symmetries().map(|g| g.to_string()).collect_vec();
+
+        assert_eq!(8, symmetries.len());
+        assert_eq!(8, symmetries.iter().unique().count());
+        assert!(symmetries.contains(&"ABC\nDEF\n".to_string()));
+        assert!(symmetries.contains(&"AD\nBE\nCF\n".to_string()));
+
+        let square: LightGrid<char> = "AA\nAA".parse().unwrap();
+        assert_eq!(
+            1,
+            square.symmetries().map(|g| g.to_string()).unique().count()
+        );
+    }
+
+    #[test]
+    fn light_grid_dimensions() {
+        let rotated = light_grid().rotate_cw();
+
+        assert_eq!(2, rotated.width());
+        assert_eq!(3, rotated.height());
+    }
+}
@@ -0,0 +1,469 @@
+use crate::utils::direction::Direction;
+use crate::utils::direction::Direction::{
+    East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West,
+};
+use crate::utils::grid_like::GridView;
+use crate::utils::grid_search::Neighbourhood;
+use crate::utils::point::Point;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Hex cell in axial coordinates, the third cube coordinate is `s = -q - r`.
+/// Both layouts share the coordinates, they only name the six directions differently.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+/// Axial offsets of the neighbours in clockwise order
+const OFFSETS: [Hex; 6] = [
+    Hex::new(0, -1),
+    Hex::new(1, -1),
+    Hex::new(1, 0),
+    Hex::new(0, 1),
+    Hex::new(-1, 1),
+    Hex::new(-1, 0),
+];
+
+impl Hex {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(q: isize, r: isize) -> Self {
+        Self { q, r }
+    }
+
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Self {
+        assert_eq!(0, q + r + s, "Cube coordinates must sum up to zero");
+
+        Self::new(q, r)
+    }
+
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    pub fn cube(&self) -> (isize, isize, isize) {
+        (self.q, self.r, self.s())
+    }
+
+    /// Number of steps needed to get to the other hex
+    pub fn distance(&self, other: &Self) -> isize {
+        (*self - *other).length()
+    }
+
+    /// Number of steps from the origin
+    pub fn length(&self) -> isize {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    /// All six adjacent hexes in clockwise order
+    pub fn neighbours(&self) -> [Self; 6] {
+        OFFSETS.map(|offset| *self + offset)
+    }
+
+    pub fn step(&self, layout: HexLayout, direction: Direction) -> Self {
+        *self
+            + layout
+                .offset(direction)
+                .expect("Direction is not a hex direction")
+    }
+
+    pub fn walk(&self, layout: HexLayout, steps: &[Direction]) -> Self {
+        steps
+            .iter()
+            .fold(*self, |hex, direction| hex.step(layout, *direction))
+    }
+
+    /// Rotation by 60 degrees around the origin
+    pub fn rotate_cw(&self) -> Self {
+        Self::new(-self.r, -self.s())
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        Self::new(-self.s(), -self.q)
+    }
+
+    /// Rotation by `times` * 60 degrees clockwise around the center, negative is counter-clockwise
+    pub fn rotate_around(&self, center: &Self, times: isize) -> Self {
+        let relative = *self - *center;
+
+        let rotated = (0..times.rem_euclid(6)).fold(relative, |hex, _| hex.rotate_cw());
+
+        rotated + *center
+    }
+
+    /// Hexes exactly `radius` steps away, starting in the first direction and going clockwise
+    pub fn ring(&self, radius: isize) -> Vec<Self> {
+        if radius == 0 {
+            return vec![*self];
+        }
+
+        let mut current = *self + OFFSETS[0] * radius;
+        let mut ring = Vec::new();
+
+        for i in 0..6 {
+            for _ in 0..radius {
+                ring.push(current);
+                current = current + OFFSETS[(i + 2) % 6];
+            }
+        }
+
+        ring
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Mul<isize> for Hex {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s())
+    }
+}
+
+/// `q` is stored as `x` and `r` as `y`, so hexes can live in the square-lattice utilities
+impl From<Hex> for Point {
+    fn from(hex: Hex) -> Self {
+        Point::new(hex.q, hex.r)
+    }
+}
+
+impl From<Point> for Hex {
+    fn from(point: Point) -> Self {
+        Hex::new(point.x, point.y)
+    }
+}
+
+/// Orientation of hexes, it decides which six compass directions exist.
+/// Flat-top hexes have `n` and `s` neighbours, pointy-top ones have `e` and `w`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HexLayout {
+    FlatTop,
+    PointyTop,
+}
+
+impl HexLayout {
+    /// Six directions of the layout in clockwise order, matching `Hex::neighbours`
+    pub fn directions(&self) -> [Direction; 6] {
+        match self {
+            Self::FlatTop => [North, NorthEast, SouthEast, South, SouthWest, NorthWest],
+            Self::PointyTop => [NorthWest, NorthEast, East, SouthEast, SouthWest, West],
+        }
+    }
+
+    /// None for the two compass directions which do not exist in the layout
+    pub fn offset(&self, direction: Direction) -> Option<Hex> {
+        self.directions()
+            .iter()
+            .position(|d| *d == direction)
+            .map(|i| OFFSETS[i])
+    }
+
+    /// Direction after turning `times` * 60 degrees clockwise, negative is counter-clockwise
+    pub fn rotate(&self, direction: Direction, times: isize) -> Direction {
+        let directions = self.directions();
+        let i = directions
+            .iter()
+            .position(|d| *d == direction)
+            .expect("Direction is not a hex direction");
+
+        directions[(i as isize + times).rem_euclid(6) as usize]
+    }
+
+    pub fn neighbourhood(&self) -> Neighbourhood {
+        Neighbourhood::Custom(self.directions().to_vec())
+    }
+
+    pub fn parse_direction(&self, value: &str) -> Result<Direction, String> {
+        let direction = match value {
+            "n" => North,
+            "ne" => NorthEast,
+            "e" => East,
+            "se" => SouthEast,
+            "s" => South,
+            "sw" => SouthWest,
+            "w" => West,
+            "nw" => NorthWest,
+            _ => return Err(format!("Unknown hex direction '{}'", value)),
+        };
+
+        match self.offset(direction) {
+            Some(_) => Ok(direction),
+            None => Err(format!(
+                "Direction '{}' does not exist in {:?} layout",
+                value, self
+            )),
+        }
+    }
+
+    /// Steps separated by commas like `ne,se,nw` or written together like `esenee`
+    pub fn parse_steps(&self, input: &str) -> Result<Vec<Direction>, String> {
+        let mut steps = Vec::new();
+
+        for chunk in input.trim().split(',').map(str::trim) {
+            let mut rest = chunk;
+
+            while !rest.is_empty() {
+                let length = match rest.as_bytes() {
+                    [b'n' | b's', b'e' | b'w', ..] => 2,
+                    _ => rest.chars().next().unwrap().len_utf8(),
+                };
+
+                steps.push(self.parse_direction(&rest[..length])?);
+                rest = &rest[length..];
+            }
+        }
+
+        Ok(steps)
+    }
+}
+
+/// Sparse hex grid, only existing cells are stored.
+/// As a `GridView` its points are axial coordinates and it steps only in the six hex directions.
+#[derive(Debug, Clone)]
+pub struct HexGrid<T> {
+    layout: HexLayout,
+    cells: HashMap<Hex, T>,
+}
+
+impl<T> HexGrid<T> {
+    #[allow(dead_code)]
+    pub fn new(layout: HexLayout) -> Self {
+        Self {
+            layout,
+            cells: HashMap::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn layout(&self) -> HexLayout {
+        self.layout
+    }
+
+    #[allow(dead_code)]
+    pub fn get(&self, hex: &Hex) -> Option<&T> {
+        self.cells.get(hex)
+    }
+
+    #[allow(dead_code)]
+    pub fn insert(&mut self, hex: Hex, value: T) -> Option<T> {
+        self.cells.insert(hex, value)
+    }
+
+    #[allow(dead_code)]
+    pub fn remove(&mut self, hex: &Hex) -> Option<T> {
+        self.cells.remove(hex)
+    }
+
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = (&Hex, &T)> {
+        self.cells.iter()
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Neighbours which exist in the grid
+    pub fn adjacent(&self, hex: &Hex) -> impl Iterator<Item = Hex> + '_ {
+        hex.neighbours()
+            .into_iter()
+            .filter(|neighbour| self.cells.contains_key(neighbour))
+    }
+}
+
+impl<T> FromIterator<(Hex, T)> for HexGrid<T> {
+    /// Collected grids use the flat-top layout, use `HexGrid::new` for the other one
+    fn from_iter<I: IntoIterator<Item = (Hex, T)>>(iter: I) -> Self {
+        Self {
+            layout: HexLayout::FlatTop,
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Hex, T)> for HexGrid<T> {
+    fn extend<I: IntoIterator<Item = (Hex, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
+
+impl<T> GridView for HexGrid<T> {
+    type Cell = T;
+
+    fn get_for_point(&self, point: &Point) -> Option<&Self::Cell> {
+        self.cells.get(&Hex::from(*point))
+    }
+
+    /// Searches step only in the six directions of the layout
+    fn neighbourhood(&self) -> Neighbourhood {
+        self.layout.neighbourhood()
+    }
+
+    fn step(&self, point: &Point, direction: Direction) -> Option<Point> {
+        let next = Hex::from(*point) + self.layout.offset(direction)?;
+
+        self.cells.get(&next).map(|_| next.into())
+    }
+
+    /// All six hex neighbours inside the grid
+    fn neighbours(&self, point: &Point) -> impl Iterator<Item = Point> {
+        self.adjacent(&Hex::from(*point)).map(Point::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::direction::Direction::{East, North, SouthEast, West};
+    use crate::utils::grid_like::GridView;
+    use crate::utils::grid_search::GridSearch;
+    use crate::utils::hex::{Hex, HexGrid, HexLayout};
+    use crate::utils::point::Point;
+
+    fn distance_after(steps: &str) -> isize {
+        let steps = HexLayout::FlatTop.parse_steps(steps).unwrap();
+
+        Hex::ORIGIN.walk(HexLayout::FlatTop, &steps).length()
+    }
+
+    #[test]
+    fn flat_top_walks() {
+        assert_eq!(3, distance_after("ne,ne,ne"));
+        assert_eq!(0, distance_after("ne,ne,sw,sw"));
+        assert_eq!(2, distance_after("ne,ne,s,s"));
+        assert_eq!(3, distance_after("se,sw,se,sw,sw"));
+    }
+
+    #[test]
+    fn pointy_top_walks() {
+        let layout = HexLayout::PointyTop;
+
+        let steps = layout.parse_steps("esew").unwrap();
+        assert_eq!(
+            Hex::ORIGIN.step(layout, SouthEast),
+            Hex::ORIGIN.walk(layout, &steps)
+        );
+
+        let steps = layout.parse_steps("nwwswee").unwrap();
+        assert_eq!(Hex::ORIGIN, Hex::ORIGIN.walk(layout, &steps));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err("Direction 'e' does not exist in FlatTop layout".to_string()),
+            HexLayout::FlatTop.parse_steps("ne,e")
+        );
+        assert_eq!(
+            Err("Unknown hex direction 'é'".to_string()),
+            HexLayout::PointyTop.parse_steps("eé")
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        let hex = Hex::new(2, -1);
+
+        assert_eq!(Hex::new(1, 1), hex.rotate_cw());
+        assert_eq!(hex, hex.rotate_cw().rotate_ccw());
+        assert_eq!(-hex, hex.rotate_around(&Hex::ORIGIN, 3));
+        assert_eq!(hex, hex.rotate_around(&Hex::new(5, 5), -6));
+        assert_eq!(hex.rotate_ccw(), hex.rotate_around(&Hex::ORIGIN, -1));
+
+        assert_eq!(East, HexLayout::PointyTop.rotate(West, 3));
+        assert_eq!(SouthEast, HexLayout::FlatTop.rotate(North, 2));
+    }
+
+    #[test]
+    fn neighbours_and_rings() {
+        let hex = Hex::new(3, -7);
+
+        assert!(hex.neighbours().iter().all(|n| hex.distance(n) == 1));
+        assert_eq!(hex.neighbours().to_vec(), hex.ring(1));
+        assert_eq!(18, hex.ring(3).len());
+        assert!(hex.ring(3).iter().all(|n| hex.distance(n) == 3));
+    }
+
+    #[test]
+    fn search_over_hex_grid() {
+        let grid: HexGrid<bool> = Hex::ORIGIN
+            .ring(2)
+            .into_iter()
+            .chain(Hex::ORIGIN.ring(1))
+            .chain(Hex::ORIGIN.ring(0))
+            .map(|hex| (hex, hex != Hex::new(1, 0)))
+            .collect();
+
+        assert_eq!(6, grid.neighbours(&Point::from(Hex::new(0, -1))).count());
+
+        let distances =
+            GridSearch::passable(&grid, |_, open| *open).distances([Point::from(Hex::new(0, -1))]);
+
+        assert_eq!(18, distances.len());
+        assert_eq!(Some(3), distances.distance(&Point::from(Hex::new(1, 1))));
+        assert_eq!(None, distances.distance(&Point::from(Hex::new(1, 0))));
+    }
+
+    #[test]
+    fn flood_fill_over_hex_grid() {
+        // ring of walls at distance 2 encloses the seven hexes around the origin
+        let grid: HexGrid<bool> = (0..=3)
+            .flat_map(|radius| Hex::ORIGIN.ring(radius))
+            .map(|hex| (hex, hex.distance(&Hex::ORIGIN) != 2))
+            .collect();
+
+        assert_eq!(HexLayout::FlatTop, grid.layout());
+
+        let region = grid.flood_fill(Point::from(Hex::ORIGIN), |_, open| *open);
+
+        assert_eq!(7, region.len());
+        assert!(region
+            .iter()
+            .all(|point| Hex::from(*point).distance(&Hex::ORIGIN) <= 1));
+
+        let mut pointy = HexGrid::new(HexLayout::PointyTop);
+        pointy.extend(grid.iter().map(|(hex, open)| (*hex, *open)));
+
+        assert_eq!(
+            region,
+            pointy.flood_fill(Point::from(Hex::ORIGIN), |_, open| *open)
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use crate::utils::range::Range;
+use std::fmt;
+use std::fmt::Display;
+
+/// Axis aligned box in N dimensions, every axis is an inclusive range
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HyperBox<const N: usize> {
+    axes: [Range; N],
+}
+
+impl<const N: usize> HyperBox<N> {
+    pub fn new(axes: [Range; N]) -> Self {
+        Self { axes }
+    }
+
+    /// Every axis spans the same range
+    pub fn cube(range: Range) -> Self {
+        Self::new([range; N])
+    }
+
+    pub fn axes(&self) -> &[Range; N] {
+        &self.axes
+    }
+
+    pub fn axis(&self, axis: usize) -> Range {
+        self.axes[axis]
+    }
+
+    pub fn with_axis(&self, axis: usize, range: Range) -> Self {
+        let mut axes = self.axes;
+        axes[axis] = range;
+
+        Self::new(axes)
+    }
+
+    /// Number of integer points inside, u128 as it easily overflows for a few wide axes
+    pub fn volume(&self) -> u128 {
+        self.axes.iter().map(|r| r.len() as u128).product()
+    }
+
+    pub fn contains(&self, point: &[isize; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point.iter())
+            .all(|(range, value)| range.contains(*value))
+    }
+
+    #[allow(dead_code)]
+    pub fn collide(&self, other: &Self) -> bool {
+        self.axes
+            .iter()
+            .zip(other.axes.iter())
+            .all(|(a, b)| a.collide(b))
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+
+        for (axis, range) in axes.iter_mut().enumerate() {
+            *range = range.intersect(&other.axes[axis]).ok()?;
+        }
+
+        Some(Self::new(axes))
+    }
+
+    /// Cuts the box in two: values on the axis lower than `at` and the rest.
+    /// A side is None when there is nothing left in it.
+    pub fn split(&self, axis: usize, at: isize) -> (Option<Self>, Option<Self>) {
+        let range = self.axes[axis];
+
+        let lower = Range::new(range.start(), range.end().min(at - 1))
+            .ok()
+            .map(|r| self.with_axis(axis, r));
+        let upper = Range::new(range.start().max(at), range.end())
+            .ok()
+            .map(|r| self.with_axis(axis, r));
+
+        (lower, upper)
+    }
+
+    /// Disjoint boxes covering the part of self which is not in other
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersect(other) else {
+            return vec![*self];
+        };
+
+        let mut result = Vec::new();
+        let mut remaining = *self;
+
+        for axis in 0..N {
+            for piece in remaining.axes[axis].diff(&common.axes[axis]) {
+                result.push(remaining.with_axis(axis, piece));
+            }
+
+            remaining = remaining.with_axis(axis, common.axes[axis]);
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> Display for HyperBox<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let axes: Vec<String> = self.axes.iter().map(|r| r.to_string()).collect();
+
+        write!(f, "[{}]", axes.join(" x "))
+    }
+}
+
+/// Union of boxes kept as a list of disjoint boxes, so volumes can be summed
+#[derive(Debug, Clone, Default)]
+pub struct BoxSet<const N: usize> {
+    boxes: Vec<HyperBox<N>>,
+}
+
+impl<const N: usize> BoxSet<N> {
+    pub fn new() -> Self {
+        Self { boxes: Vec::new() }
+    }
+
+    pub fn insert(&mut self, hyper_box: HyperBox<N>) {
+        let mut pieces = vec![hyper_box];
+
+        for existing in &self.boxes {
+            pieces = pieces
+                .iter()
+                .flat_map(|piece| piece.subtract(existing))
+                .collect();
+        }
+
+        self.boxes.extend(pieces);
+    }
+
+    #[allow(dead_code)]
+    pub fn remove(&mut self, hyper_box: &HyperBox<N>) {
+        self.boxes = self
+            .boxes
+            .iter()
+            .flat_map(|existing| existing.subtract(hyper_box))
+            .collect();
+    }
+
+    #[allow(dead_code)]
+    pub fn volume(&self) -> u128 {
+        self.boxes.iter().map(HyperBox::volume).sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, point: &[isize; N]) -> bool {
+        self.boxes.iter().any(|b| b.contains(point))
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.boxes.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &HyperBox<N>> {
+        self.boxes.iter()
+    }
+}
+
+impl<const N: usize> FromIterator<HyperBox<N>> for BoxSet<N> {
+    fn from_iter<I: IntoIterator<Item = HyperBox<N>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<const N: usize> Extend<HyperBox<N>> for BoxSet<N> {
+    fn extend<I: IntoIterator<Item = HyperBox<N>>>(&mut self, iter: I) {
+        for hyper_box in iter {
+            self.insert(hyper_box);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::hyper_box::{BoxSet, HyperBox};
+    use crate::utils::range::Range;
+
+    fn cuboid(x: (isize, isize), y: (isize, isize), z: (isize, isize)) -> HyperBox<3> {
+        HyperBox::new([
+            Range::new(x.0, x.1).unwrap(),
+            Range::new(y.0, y.1).unwrap(),
+            Range::new(z.0, z.1).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn volume() {
+        assert_eq!(27, cuboid((10, 12), (10, 12), (10, 12)).volume());
+        assert_eq!(
+            4000u128.pow(4),
+            HyperBox::<4>::cube(Range::new(1, 4000).unwrap()).volume()
+        );
+        assert_eq!(
+            "[(1, 2) x (3, 4)]",
+            HyperBox::new([Range::new(1, 2).unwrap(), Range::new(3, 4).unwrap()]).to_string()
+        );
+    }
+
+    #[test]
+    fn intersect_and_contains() {
+        let a = cuboid((0, 4), (0, 4), (0, 4));
+        let b = cuboid((3, 6), (-2, 1), (4, 4));
+
+        assert_eq!(Some(cuboid((3, 4), (0, 1), (4, 4))), a.intersect(&b));
+        assert_eq!(None, a.intersect(&cuboid((5, 6), (0, 4), (0, 4))));
+        assert!(a.contains(&[4, 0, 2]));
+        assert!(!a.contains(&[4, 5, 2]));
+    }
+
+    #[test]
+    fn split() {
+        let a = cuboid((0, 4), (0, 4), (0, 4));
+
+        assert_eq!(
+            (
+                Some(cuboid((0, 4), (0, 1), (0, 4))),
+                Some(cuboid((0, 4), (2, 4), (0, 4)))
+            ),
+            a.split(1, 2)
+        );
+        assert_eq!((None, Some(a)), a.split(0, 0));
+        assert_eq!((Some(a), None), a.split(2, 5));
+    }
+
+    #[test]
+    fn subtract() {
+        let a = cuboid((0, 4), (0, 4), (0, 4));
+        let b = cuboid((1, 2), (1, 2), (1, 2));
+
+        let pieces = a.subtract(&b);
+
+        assert_eq!(6, pieces.len());
+        assert_eq!(125 - 8, pieces.iter().map(HyperBox::volume).sum::<u128>());
+        assert!(pieces
+            .iter()
+            .enumerate()
+            .all(|(i, p)| pieces[i + 1..].iter().all(|q| !p.collide(q))));
+        assert_eq!(vec![a], a.subtract(&cuboid((5, 6), (0, 4), (0, 4))));
+        assert!(b.subtract(&a).is_empty());
+    }
+
+    #[test]
+    fn reactor_reboot() {
+        let mut reactor = BoxSet::new();
+
+        reactor.insert(cuboid((10, 12), (10, 12), (10, 12)));
+        assert_eq!(27, reactor.volume());
+
+        reactor.insert(cuboid((11, 13), (11, 13), (11, 13)));
+        assert_eq!(46, reactor.volume());
+
+        reactor.remove(&cuboid((9, 11), (9, 11), (9, 11)));
+        assert_eq!(38, reactor.volume());
+
+        reactor.insert(cuboid((10, 10), (10, 10), (10, 10)));
+        assert_eq!(39, reactor.volume());
+        assert!(reactor.contains(&[10, 10, 10]));
+        assert!(!reactor.contains(&[11, 10, 10]));
+    }
+}
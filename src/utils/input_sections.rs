@@ -0,0 +1,331 @@
+use crate::utils::cell_legend::CellLegend;
+use crate::utils::grid::Grid;
+use crate::utils::light_grid::LightGrid;
+use crate::utils::point::Point;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Puzzle input split into blocks separated by blank lines, whitespace-only lines count as blank.
+/// Lines are kept as they are, apart from Windows line endings, so trailing spaces of grids survive.
+/// Extra blank lines between sections are ignored.
+#[derive(Debug, Clone)]
+pub struct InputSections {
+    sections: Vec<Section>,
+}
+
+impl InputSections {
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Section> {
+        self.sections.iter()
+    }
+
+    /// Section by 0-based index, the error uses 1-based numbers like the rest of messages
+    #[allow(dead_code)]
+    pub fn get(&self, index: usize) -> Result<&Section, String> {
+        self.sections.get(index).ok_or_else(|| {
+            format!(
+                "Section {} does not exist, input has {} sections",
+                index + 1,
+                self.sections.len()
+            )
+        })
+    }
+
+    /// Input which must consist of exactly two sections
+    pub fn pair(&self) -> Result<(&Section, &Section), String> {
+        match self.sections.as_slice() {
+            [first, second] => Ok((first, second)),
+            _ => Err(format!(
+                "Expected 2 sections, input has {}",
+                self.sections.len()
+            )),
+        }
+    }
+}
+
+impl From<&str> for InputSections {
+    fn from(input: &str) -> Self {
+        let mut sections: Vec<Section> = Vec::new();
+        let mut current: Option<Section> = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            if line.trim().is_empty() {
+                sections.extend(current.take());
+                continue;
+            }
+
+            current
+                .get_or_insert_with(|| Section {
+                    number: sections.len() + 1,
+                    first_line: i + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line.to_string());
+        }
+        sections.extend(current);
+
+        Self { sections }
+    }
+}
+
+/// Block of non-empty lines, remembers where in the input it starts
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    number: usize,
+    first_line: usize,
+    lines: Vec<String>,
+}
+
+impl Section {
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Fails on the first unknown cell
+    pub fn grid<T>(&self) -> Result<Grid<T>, String>
+    where
+        T: CellLegend + PartialEq,
+    {
+        let mut cells: Vec<(Point, T)> = Vec::new();
+
+        for (i, line) in self.lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let cell = T::from_char(c).ok_or_else(|| {
+                    self.error_at(i, format!("unknown cell '{}' in column {}", c, x + 1))
+                })?;
+
+                cells.push((Point::new(x as isize, i as isize), cell));
+            }
+        }
+
+        Ok(cells.into_iter().collect())
+    }
+
+    #[allow(dead_code)]
+    pub fn grid_with<T>(&self, func: fn(char) -> T) -> Grid<T>
+    where
+        T: PartialEq,
+    {
+        Grid::from_custom(self.text().as_str(), func)
+    }
+
+    /// Fails if the lines have different lengths
+    #[allow(dead_code)]
+    pub fn light_grid_with<T, F>(&self, func: F) -> Result<LightGrid<T>, String>
+    where
+        T: Clone,
+        F: FnMut(u8) -> T,
+    {
+        let width = self.lines.first().map_or(0, |line| line.len());
+
+        if let Some((i, line)) = self
+            .lines
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.len() != width)
+        {
+            return Err(self.error_at(
+                i,
+                format!("expected {} cells in a row, got {}", width, line.len()),
+            ));
+        }
+
+        Ok(LightGrid::from_str_with(self.text().as_str(), func))
+    }
+
+    /// Every line parsed as a whole
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse()
+                    .map_err(|e| self.error_at(i, format!("cannot parse '{}': {}", line, e)))
+            })
+            .collect()
+    }
+
+    /// All numbers separated by whitespace or commas
+    #[allow(dead_code)]
+    pub fn numbers<T>(&self) -> Result<Vec<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut numbers: Vec<T> = Vec::new();
+
+        for (i, line) in self.lines.iter().enumerate() {
+            for value in line
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|v| !v.is_empty())
+            {
+                let number = value
+                    .parse()
+                    .map_err(|e| self.error_at(i, format!("invalid number '{}': {}", value, e)))?;
+
+                numbers.push(number);
+            }
+        }
+
+        Ok(numbers)
+    }
+
+    /// 1-based position of the section in the input
+    #[allow(dead_code)]
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// 1-based line of the input where the section starts
+    #[allow(dead_code)]
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    fn error_at(&self, index: usize, message: String) -> String {
+        format!(
+            "Section {}, line {}: {}",
+            self.number,
+            self.first_line + index,
+            message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::cell_legend::cell_legend;
+    use crate::utils::grid::Grid;
+    use crate::utils::input_sections::InputSections;
+
+    cell_legend! {
+        enum Tile {
+            Empty = '.',
+            Wall = '#',
+        }
+    }
+
+    const EXAMPLE: &str = "#.\r\n.#\r\n\r\n\r\n1, 2\n3 4\n\n\nabc\n\n";
+
+    #[test]
+    fn split() {
+        let sections = InputSections::from(EXAMPLE);
+
+        assert_eq!(3, sections.len());
+        assert_eq!("#.\n.#", sections.get(0).unwrap().text());
+        assert_eq!(vec!["1, 2", "3 4"], sections.get(1).unwrap().lines());
+        assert_eq!(5, sections.get(1).unwrap().first_line());
+        assert_eq!(3, sections.get(2).unwrap().number());
+    }
+
+    #[test]
+    fn trailing_spaces_are_kept() {
+        let sections = InputSections::from("#.  \r\n .# \n\nabc\r");
+
+        assert_eq!(vec!["#.  ", " .# "], sections.get(0).unwrap().lines());
+        assert_eq!(vec!["abc"], sections.get(1).unwrap().lines());
+    }
+
+    #[test]
+    fn whitespace_only_lines_separate_sections() {
+        let sections = InputSections::from("a\n  \nb\n\t\r\nc");
+
+        assert_eq!(3, sections.len());
+        assert_eq!("b", sections.get(1).unwrap().text());
+        assert_eq!(5, sections.get(2).unwrap().first_line());
+    }
+
+    #[test]
+    fn missing_section() {
+        let sections = InputSections::from(EXAMPLE);
+
+        assert_eq!(
+            Err("Section 4 does not exist, input has 3 sections".to_string()),
+            sections.get(3)
+        );
+        assert_eq!(
+            Err("Expected 2 sections, input has 3".to_string()),
+            sections.pair()
+        );
+    }
+
+    #[test]
+    fn grids() {
+        let sections = InputSections::from(EXAMPLE);
+        let section = sections.get(0).unwrap();
+
+        let grid: Grid<char> = section.grid().unwrap();
+        assert_eq!("#.\n.#\n", grid.to_string());
+
+        let light_grid = section.light_grid_with(|c| c == b'#').unwrap();
+        assert_eq!(Some(&true), light_grid.get(1, 1));
+    }
+
+    #[test]
+    fn unknown_grid_cell() {
+        let sections = InputSections::from("..\n\n.#\n.x\n");
+
+        assert_eq!(
+            "Section 2, line 4: unknown cell 'x' in column 2",
+            sections.get(1).unwrap().grid::<Tile>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn ragged_light_grid() {
+        let sections = InputSections::from("..\n\n...\n..\n");
+
+        assert_eq!(
+            "Section 2, line 4: expected 3 cells in a row, got 2",
+            sections.get(1).unwrap().light_grid_with(|c| c).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        let sections = InputSections::from(EXAMPLE);
+
+        assert_eq!(
+            Ok(vec![1, 2, 3, 4]),
+            sections.get(1).unwrap().numbers::<u32>()
+        );
+        assert_eq!(
+            "Section 3, line 9: invalid number 'abc': invalid digit found in string",
+            sections.get(2).unwrap().numbers::<u32>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_lines() {
+        let sections = InputSections::from("5\n-3\n\nx");
+
+        assert_eq!(
+            Ok(vec![5, -3]),
+            sections.get(0).unwrap().parse_lines::<i32>()
+        );
+        assert_eq!(
+            "Section 2, line 4: cannot parse 'x': invalid digit found in string",
+            sections.get(1).unwrap().parse_lines::<i32>().unwrap_err()
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use crate::utils::grid_like::{GridLike, GridView};
+use crate::utils::grid_transform::GridTransform;
+use crate::utils::point::Point;
+use crate::utils::surface_range::SurfaceRange;
 use std::fmt;
 use std::str::FromStr;
 
@@ -32,6 +36,7 @@ where
         let lines: Vec<&str> = input.lines().collect();
         let height = lines.len();
         let width = lines.first().map(|line| line.len()).unwrap_or(0);
+        assert_rectangular(lines.iter().map(|line| line.len()), width);
 
         let cells: Vec<T> = lines
             .iter()
@@ -97,6 +102,7 @@ where
         })
     }
 
+    #[allow(dead_code)]
     pub fn map<U, F>(&self, mut func: F) -> LightGrid<U>
     where
         F: FnMut(usize, usize, &T) -> U,
@@ -123,25 +129,22 @@ where
         self.cells.iter().filter(|cell| predicate(cell)).count()
     }
 
-    pub fn adjacent_with_diagonals(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-        let mut result = Vec::with_capacity(8);
-
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-
-                let nx = x as isize + dx;
-                let ny = y as isize + dy;
-
-                if nx >= 0 && nx < self.width as isize && ny >= 0 && ny < self.height as isize {
-                    result.push((nx as usize, ny as usize));
-                }
-            }
-        }
-
-        result
+    /// Neighbours in all eight directions which are inside the grid
+    #[allow(dead_code)]
+    pub fn adjacent_with_diagonals(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&(dx, dy)| dx != 0 || dy != 0)
+            .filter_map(move |(dx, dy)| {
+                let nx = x.checked_add_signed(dx)?;
+                let ny = y.checked_add_signed(dy)?;
+
+                (nx < self.width && ny < self.height).then_some((nx, ny))
+            })
     }
 }
 
@@ -154,6 +157,30 @@ where
     }
 }
 
+/// `LightGrid` always starts at (0, 0), so remapped cells are moved to the origin
+impl<T> GridTransform for LightGrid<T>
+where
+    T: Clone,
+{
+    fn remap<F>(&self, surface: SurfaceRange, source: F) -> Self
+    where
+        F: Fn(Point) -> Point,
+    {
+        let cells = surface
+            .rows()
+            .iter()
+            .flat_map(|y| surface.columns().iter().map(move |x| Point::new(x, y)))
+            .map(|p| self.get_for_point(&source(p)).unwrap().clone())
+            .collect();
+
+        Self::new(
+            cells,
+            surface.columns().len() as usize,
+            surface.rows().len() as usize,
+        )
+    }
+}
+
 impl FromStr for LightGrid<u8> {
     type Err = std::convert::Infallible;
 
@@ -175,13 +202,77 @@ where
     T: fmt::Display + Clone,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                write!(f, "{}", self.get(x, y).unwrap())?;
-            }
-            writeln!(f)?;
+        self.fmt_cells(f)
+    }
+}
+
+impl<T> GridView for LightGrid<T>
+where
+    T: Clone,
+{
+    type Cell = T;
+
+    fn get_for_point(&self, point: &Point) -> Option<&T> {
+        if point.x < 0 || point.y < 0 {
+            return None;
+        }
+
+        self.get(point.x as usize, point.y as usize)
+    }
+}
+
+impl<T> GridLike for LightGrid<T>
+where
+    T: Clone,
+{
+    fn surface(&self) -> Option<SurfaceRange> {
+        (self.width > 0 && self.height > 0)
+            .then(|| SurfaceRange::rectangle(self.width as isize, self.height as isize))
+    }
+
+    fn set_for_point(&mut self, point: &Point, value: T) {
+        if point.x >= 0 && point.y >= 0 {
+            self.set(point.x as usize, point.y as usize, value);
         }
-        Ok(())
+    }
+
+    fn parse_with<F>(input: &str, mut func: F) -> Self
+    where
+        F: FnMut(char) -> T,
+    {
+        let lines: Vec<&str> = input.lines().collect();
+        let height = lines.len();
+        let width = lines.first().map(|line| line.chars().count()).unwrap_or(0);
+        assert_rectangular(lines.iter().map(|line| line.chars().count()), width);
+
+        let cells: Vec<T> = lines
+            .iter()
+            .flat_map(|line| line.chars())
+            .map(&mut func)
+            .collect();
+
+        Self::new(cells, width, height)
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+}
+
+fn assert_rectangular(line_widths: impl Iterator<Item = usize>, width: usize) {
+    for (y, line_width) in line_widths.enumerate() {
+        assert_eq!(
+            width,
+            line_width,
+            "Line {} has {} cells, expected {} like the first one",
+            y + 1,
+            line_width,
+            width
+        );
     }
 }
 
@@ -214,11 +305,11 @@ mod tests {
 
         // Center cell should have 8 neighbors
         let neighbors = grid.adjacent_with_diagonals(1, 1);
-        assert_eq!(neighbors.len(), 8);
+        assert_eq!(neighbors.count(), 8);
 
         // Corner cell should have 3 neighbors
         let neighbors = grid.adjacent_with_diagonals(0, 0);
-        assert_eq!(neighbors.len(), 3);
+        assert_eq!(neighbors.count(), 3);
     }
 
     #[test]
@@ -241,4 +332,24 @@ mod tests {
         assert_eq!(grid.get(0, 0), Some(&'A'));
         assert_eq!(grid.get(1, 1), Some(&'D'));
     }
+
+    #[test]
+    fn test_empty_grid() {
+        let grid: LightGrid<char> = "".parse().unwrap();
+
+        assert_eq!(None, grid.surface());
+        assert_eq!(0, GridLike::width(&grid));
+        assert_eq!(0, grid.points().count());
+        assert_eq!("", grid.to_string());
+        assert_eq!(
+            Some(SurfaceRange::square(0)),
+            LightGrid::new(vec!['a'], 1, 1).surface()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Line 2 has 3 cells, expected 2 like the first one")]
+    fn ragged_input() {
+        let _: LightGrid<char> = "ab\ncde".parse().unwrap();
+    }
 }
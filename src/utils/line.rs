@@ -1,4 +1,6 @@
+use crate::utils::geometry::is_on_segment;
 use crate::utils::point::Point;
+use crate::utils::rational::Rational;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Line {
@@ -21,18 +23,19 @@ impl Line {
         Self { start, end }
     }
 
-    pub fn intersect(self, other: &Self) -> Option<Point> {
-        let a = self.start;
-        let b = self.end;
-        let a1 = b.y - a.y;
-        let b1 = a.x - b.x;
-        let c1 = a1 * a.x + b1 * a.y;
-
-        let c = other.start;
-        let d = other.end;
-        let a2 = d.y - c.y;
-        let b2 = c.x - d.x;
-        let c2 = a2 * c.x + b2 * c.y;
+    /// Exact crossing point of both lines extended past their ends, None if they are parallel
+    #[allow(dead_code)]
+    pub fn intersect(self, other: &Self) -> Option<[Rational; 2]> {
+        // i128 keeps the products exact for coordinates far beyond what f64 can represent
+        let (a, b) = (self.start, self.end);
+        let a1 = (b.y - a.y) as i128;
+        let b1 = (a.x - b.x) as i128;
+        let c1 = a1 * a.x as i128 + b1 * a.y as i128;
+
+        let (c, d) = (other.start, other.end);
+        let a2 = (d.y - c.y) as i128;
+        let b2 = (c.x - d.x) as i128;
+        let c2 = a2 * c.x as i128 + b2 * c.y as i128;
 
         let determinant = a1 * b2 - a2 * b1;
 
@@ -40,25 +43,15 @@ impl Line {
             return None;
         }
 
-        let x = (b2 as f64 * c1 as f64 - b1 as f64 * c2 as f64) / determinant as f64;
-        let y = (a1 as f64 * c2 as f64 - a2 as f64 * c1 as f64) / determinant as f64;
-
-        Some(Point::new(x as isize, y as isize))
+        Some([
+            Rational::new(b2 * c1 - b1 * c2, determinant),
+            Rational::new(a1 * c2 - a2 * c1, determinant),
+        ])
     }
 
     #[allow(dead_code)]
     pub fn is_on(&self, point: &Point) -> bool {
-        let a = self.start;
-        let b = self.end;
-        let p = *point;
-
-        let cross_product = (p.y - a.y) * (b.x - a.x) - (p.x - a.x) * (b.y - a.y);
-
-        if cross_product != 0 {
-            return false;
-        }
-
-        p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+        is_on_segment(point, &self.start, &self.end)
     }
 }
 
@@ -66,6 +59,32 @@ impl Line {
 mod tests {
     use crate::utils::line::Line;
     use crate::utils::point::Point;
+    use crate::utils::rational::Rational;
+
+    #[test]
+    fn intersect() {
+        let a = Line::new(Point::new(0, 0), Point::new(10, 10));
+        let b = Line::new(Point::new(0, 4), Point::new(4, 0));
+
+        assert_eq!(Some([Rational::from(2isize); 2]), a.intersect(&b));
+        assert_eq!(
+            Some([Rational::new(1, 2); 2]),
+            a.intersect(&Line::new(Point::new(0, 1), Point::new(1, 0)))
+        );
+        assert_eq!(
+            None,
+            a.intersect(&Line::new(Point::new(0, 1), Point::new(1, 2)))
+        );
+
+        let far = Line::new(
+            Point::new(200_000_000_000_001, 0),
+            Point::new(200_000_000_000_000, 1),
+        );
+        assert_eq!(
+            Some([Rational::new(200_000_000_000_001, 2); 2]),
+            a.intersect(&far)
+        );
+    }
 
     #[test]
     fn is_on() {
@@ -1,24 +1,43 @@
+pub mod beam_tracer;
 pub mod binary;
+pub mod cell_legend;
+pub mod cellular_automaton;
 pub mod chain_pattern_finder;
 pub mod combinatorics;
+pub mod coordinate_compression;
 pub mod crypto;
 pub mod deltoid_surface;
 pub mod direction;
 pub mod filled_region;
+pub mod geometry;
 pub mod graphs;
 pub mod grid;
+pub mod grid_like;
 pub mod grid_line;
+pub mod grid_pattern;
+pub mod grid_search;
+pub mod grid_transform;
+pub mod hex;
+pub mod hyper_box;
+pub mod input_sections;
 pub mod light_grid;
 pub mod line;
 pub mod math;
 pub mod moving_point;
 pub mod pair_generator;
+pub mod parametric_line;
 pub mod point;
 pub mod point3d;
+pub mod point_n;
 pub mod polygon;
 pub mod range;
+pub mod range_set;
+pub mod rational;
+pub mod render;
 pub mod rotation;
-pub mod shoelace_formula;
+pub mod rotation3d;
 pub mod surface_range;
 pub mod traits;
 pub mod vector;
+pub mod voxel_grid;
+pub mod wrapping_grid;
@@ -0,0 +1,315 @@
+use crate::utils::point3d::Point3D;
+use crate::utils::rational::Rational;
+use itertools::Itertools;
+use std::fmt;
+use std::fmt::Display;
+
+type Vec3 = [i128; 3];
+
+/// Line in 3D going through `position` at time 0 and moving by `velocity` per time unit.
+/// Everything is computed exactly, with i128 intermediates and rational results.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParametricLine {
+    position: Point3D,
+    velocity: Point3D,
+}
+
+/// Meeting point of two lines, times are the parameters of each line at that point
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Crossing {
+    pub time: Rational,
+    pub other_time: Rational,
+    pub point: [Rational; 3],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineRelation {
+    Crossing(Crossing),
+    Parallel,
+    /// Both lines are the same set of points
+    Coincident,
+    /// Not parallel and never meeting, only possible in 3D
+    Skew,
+}
+
+impl ParametricLine {
+    pub fn new(position: Point3D, velocity: Point3D) -> Self {
+        Self { position, velocity }
+    }
+
+    pub fn position(&self) -> Point3D {
+        self.position
+    }
+
+    #[allow(dead_code)]
+    pub fn velocity(&self) -> Point3D {
+        self.velocity
+    }
+
+    #[allow(dead_code)]
+    pub fn at(&self, time: isize) -> Point3D {
+        self.position + self.velocity * time
+    }
+
+    pub fn at_rational(&self, time: Rational) -> [Rational; 3] {
+        let (position, velocity) = (vec3(&self.position), vec3(&self.velocity));
+
+        [0, 1, 2].map(|i| Rational::from(position[i]) + time * Rational::from(velocity[i]))
+    }
+
+    /// Relation of both lines projected on the XY plane, Z is ignored.
+    /// The crossing point has the Z coordinate of self at the crossing time.
+    pub fn relation_2d(&self, other: &Self) -> LineRelation {
+        let flat = |line: &Self| {
+            Self::new(
+                Point3D::new(line.position.x, line.position.y, 0),
+                Point3D::new(line.velocity.x, line.velocity.y, 0),
+            )
+        };
+
+        match flat(self).relation(&flat(other)) {
+            LineRelation::Crossing(crossing) => LineRelation::Crossing(Crossing {
+                point: self.at_rational(crossing.time),
+                ..crossing
+            }),
+            relation => relation,
+        }
+    }
+
+    pub fn relation(&self, other: &Self) -> LineRelation {
+        let (v, w) = (vec3(&self.velocity), vec3(&other.velocity));
+        let d = sub(vec3(&other.position), vec3(&self.position));
+        let normal = cross(v, w);
+
+        if normal == [0; 3] {
+            return match cross(d, v) == [0; 3] {
+                true => LineRelation::Coincident,
+                false => LineRelation::Parallel,
+            };
+        }
+
+        if dot(d, normal) != 0 {
+            return LineRelation::Skew;
+        }
+
+        let length = Rational::from(dot(normal, normal));
+        let time = Rational::from(dot(cross(d, w), normal)) / length;
+        let other_time = Rational::from(dot(cross(d, v), normal)) / length;
+
+        LineRelation::Crossing(Crossing {
+            time,
+            other_time,
+            point: self.at_rational(time),
+        })
+    }
+
+    /// Time at which both are in the same place, like two moving objects colliding
+    pub fn collision_time(&self, other: &Self) -> Option<Rational> {
+        let d = sub(vec3(&other.position), vec3(&self.position));
+        let w = sub(vec3(&self.velocity), vec3(&other.velocity));
+
+        let Some(axis) = (0..3).find(|&i| w[i] != 0) else {
+            return (d == [0; 3]).then_some(Rational::ZERO);
+        };
+
+        (0..3)
+            .all(|i| d[i] * w[axis] == d[axis] * w[i])
+            .then(|| Rational::new(d[axis], w[axis]))
+    }
+
+    /// Line with integer position and velocity colliding with every given line.
+    /// Moving to the frame of the first line makes it a still point, so the result goes
+    /// through that point and one crossing of each plane spanned by it and another line.
+    pub fn common_line(lines: &[Self]) -> Option<Self> {
+        let origin = lines.first()?;
+        let relative = |line: &Self| {
+            (
+                sub(vec3(&line.position), vec3(&origin.position)),
+                sub(vec3(&line.velocity), vec3(&origin.velocity)),
+            )
+        };
+
+        lines[1..]
+            .iter()
+            .tuple_combinations()
+            .find_map(|(first, second)| {
+                let (p1, v1) = relative(first);
+                let (p2, v2) = relative(second);
+
+                let t1 = Self::plane_crossing_time((p1, v1), cross(p2, v2))?;
+                let t2 = Self::plane_crossing_time((p2, v2), cross(p1, v1))?;
+                if t1 == t2 {
+                    return None;
+                }
+
+                let (r1, r2) = (first.at_rational(t1), second.at_rational(t2));
+                // A fractional velocity means no common line, bail out before it grows
+                let velocity = point([0, 1, 2].map(|i| (r2[i] - r1[i]) / (t2 - t1)))?;
+                let position =
+                    point([0, 1, 2].map(|i| r1[i] - t1 * Rational::from(vec3(&velocity)[i])))?;
+
+                let candidate = Self::new(position, velocity);
+
+                lines
+                    .iter()
+                    .all(|l| candidate.collision_time(l).is_some())
+                    .then_some(candidate)
+            })
+    }
+
+    fn plane_crossing_time((position, velocity): (Vec3, Vec3), normal: Vec3) -> Option<Rational> {
+        let speed = dot(velocity, normal);
+
+        (speed != 0).then(|| Rational::new(-dot(position, normal), speed))
+    }
+}
+
+impl Display for ParametricLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} @ {}", self.position, self.velocity)
+    }
+}
+
+fn vec3(point: &Point3D) -> Vec3 {
+    [point.x as i128, point.y as i128, point.z as i128]
+}
+
+fn point(coordinates: [Rational; 3]) -> Option<Point3D> {
+    let [x, y, z] = coordinates.map(|c| c.to_integer());
+
+    Some(Point3D::new(x? as isize, y? as isize, z? as isize))
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: Vec3, b: Vec3) -> i128 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parametric_line::{LineRelation, ParametricLine};
+    use crate::utils::point3d::Point3D;
+    use crate::utils::rational::Rational;
+
+    fn line(position: (isize, isize, isize), velocity: (isize, isize, isize)) -> ParametricLine {
+        ParametricLine::new(
+            Point3D::new(position.0, position.1, position.2),
+            Point3D::new(velocity.0, velocity.1, velocity.2),
+        )
+    }
+
+    #[test]
+    fn relation_2d() {
+        let a = line((19, 13, 30), (-2, 1, -2));
+        let b = line((18, 19, 22), (-1, -1, -2));
+
+        let LineRelation::Crossing(crossing) = a.relation_2d(&b) else {
+            panic!("Lines should cross");
+        };
+
+        assert_eq!(Rational::new(7, 3), crossing.time);
+        assert_eq!(Rational::new(43, 3), crossing.point[0]);
+        assert_eq!(Rational::new(46, 3), crossing.point[1]);
+        assert!(crossing.other_time > Rational::ZERO);
+
+        let c = line((20, 25, 34), (-2, -2, -4));
+        assert_eq!(LineRelation::Parallel, b.relation_2d(&c));
+    }
+
+    #[test]
+    fn relation_in_3d() {
+        let x_axis = line((0, 0, 0), (1, 0, 0));
+
+        assert_eq!(
+            LineRelation::Skew,
+            x_axis.relation(&line((0, 0, 1), (0, 1, 0)))
+        );
+        assert_eq!(
+            LineRelation::Parallel,
+            x_axis.relation(&line((0, 1, 0), (2, 0, 0)))
+        );
+        assert_eq!(
+            LineRelation::Coincident,
+            x_axis.relation(&line((5, 0, 0), (-3, 0, 0)))
+        );
+
+        let LineRelation::Crossing(crossing) = x_axis.relation(&line((3, -2, 0), (0, 4, 0))) else {
+            panic!("Lines should cross");
+        };
+        assert_eq!(Rational::from(3i128), crossing.time);
+        assert_eq!(Rational::new(1, 2), crossing.other_time);
+    }
+
+    #[test]
+    fn stays_exact_for_large_coordinates() {
+        let a = line((200_000_000_000_001, 0, 0), (-1, 1, 0));
+        let b = line((0, 0, 0), (3, 1, 0));
+
+        let LineRelation::Crossing(crossing) = a.relation(&b) else {
+            panic!("Lines should cross");
+        };
+
+        assert_eq!(Rational::new(600_000_000_000_003, 4), crossing.point[0]);
+    }
+
+    #[test]
+    fn collision_time() {
+        let rock = line((24, 13, 10), (-3, 1, 2));
+
+        assert_eq!(
+            Some(Rational::from(5i128)),
+            rock.collision_time(&line((19, 13, 30), (-2, 1, -2)))
+        );
+        assert_eq!(None, rock.collision_time(&line((19, 13, 31), (-2, 1, -2))));
+    }
+
+    #[test]
+    fn common_line() {
+        let hails = [
+            line((19, 13, 30), (-2, 1, -2)),
+            line((18, 19, 22), (-1, -1, -2)),
+            line((20, 25, 34), (-2, -2, -4)),
+            line((12, 31, 28), (-1, -2, -1)),
+            line((20, 19, 15), (1, -5, -3)),
+        ];
+
+        assert_eq!(
+            Some(line((24, 13, 10), (-3, 1, 2))),
+            ParametricLine::common_line(&hails)
+        );
+    }
+
+    #[test]
+    fn common_line_of_large_coordinates() {
+        let mut hails = vec![
+            line((123438788958, 234577890153, 345697901291), (5, -3, 2)),
+            line((123524289201, 234555390088, 345708901318), (-40, 12, 9)),
+            line((123444356996, 234570998127, 345701434263), (3, 3, -8)),
+            line((123330788682, 234702290475, 345762901454), (17, -25, 1)),
+            line((123435088879, 234608190370, 345651001063), (-6, -6, 30)),
+            line((123390288557, 234571690149, 345745401689), (22, 5, -14)),
+        ];
+
+        assert_eq!(
+            Some(line(
+                (123456789012, 234567890123, 345678901234),
+                (-13, 7, 21)
+            )),
+            ParametricLine::common_line(&hails)
+        );
+
+        hails.push(line((0, 0, 0), (1, 1, 1)));
+        assert_eq!(None, ParametricLine::common_line(&hails));
+    }
+}
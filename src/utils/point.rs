@@ -2,6 +2,7 @@ use crate::utils::direction::Direction;
 use crate::utils::direction::Direction::{
     East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West,
 };
+use crate::utils::point_n::PointN;
 use crate::utils::vector::Vector;
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, Mul, Neg, Sub};
@@ -80,20 +81,15 @@ impl Point {
     }
 
     pub fn manhattan_distance(&self, other: &Self) -> isize {
-        (other.x - self.x).abs() + (other.y - self.y).abs()
+        PointN::from(*self).manhattan_distance(&PointN::from(*other))
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> isize {
+        PointN::from(*self).chebyshev_distance(&PointN::from(*other))
     }
 
     pub fn move_in_with_length(&self, direction: Direction, length: isize) -> Self {
-        match direction {
-            North => Self::new(self.x, self.y - length),
-            East => Self::new(self.x + length, self.y),
-            West => Self::new(self.x - length, self.y),
-            South => Self::new(self.x, self.y + length),
-            NorthEast => Self::new(self.x + length, self.y - length),
-            NorthWest => Self::new(self.x - length, self.y - length),
-            SouthEast => Self::new(self.x + length, self.y + length),
-            SouthWest => Self::new(self.x - length, self.y + length),
-        }
+        *self + direction.offset() * length
     }
 
     pub fn west(&self) -> Self {
@@ -139,7 +135,11 @@ impl Point {
             return if diff.x > 0 { East } else { West };
         }
 
-        unimplemented!("Diagonal directions are not implemented");
+        if diff.x.abs() == diff.y.abs() {
+            return Direction::try_from(Point::new(diff.x.signum(), diff.y.signum())).unwrap();
+        }
+
+        unimplemented!("{} is not in a straight line from {}", other, self);
     }
 
     #[allow(dead_code)]
@@ -150,9 +150,7 @@ impl Point {
     }
 
     fn distance(&self, other: &Self) -> f64 {
-        let diff = *self - *other;
-
-        ((diff.x.abs().pow(2) + diff.y.abs().pow(2)) as f64).sqrt()
+        (PointN::from(*self).distance_squared(&PointN::from(*other)) as f64).sqrt()
     }
 
     pub fn with_y(self, y: isize) -> Self {
@@ -166,7 +164,7 @@ impl Point {
 
 impl Display for Point {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({},{})", self.x, self.y)
+        write!(f, "{}", PointN::from(*self))
     }
 }
 
@@ -174,7 +172,7 @@ impl Add<Point> for Point {
     type Output = Point;
 
     fn add(self, rhs: Point) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
+        (PointN::from(self) + PointN::from(rhs)).into()
     }
 }
 
@@ -182,7 +180,7 @@ impl Sub<Point> for Point {
     type Output = Point;
 
     fn sub(self, rhs: Point) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
+        (PointN::from(self) - PointN::from(rhs)).into()
     }
 }
 
@@ -190,7 +188,7 @@ impl Mul<isize> for Point {
     type Output = Point;
 
     fn mul(self, rhs: isize) -> Self::Output {
-        Self::new(self.x * rhs, self.y * rhs)
+        (PointN::from(self) * rhs).into()
     }
 }
 
@@ -198,23 +196,15 @@ impl Neg for Point {
     type Output = Point;
 
     fn neg(self) -> Self::Output {
-        Self::new(-self.x, -self.y)
+        (-PointN::from(self)).into()
     }
 }
 
 impl FromStr for Point {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s.split_once(',').ok_or(())?;
-
-        Ok(Self::new(x.parse().unwrap(), y.parse().unwrap()))
-    }
-}
-
-impl From<Point> for (usize, usize) {
-    fn from(point: Point) -> Self {
-        (point.x as usize, point.y as usize)
+        s.parse::<PointN<isize, 2>>().map(Self::from)
     }
 }
 
@@ -303,5 +293,9 @@ mod tests {
         assert_eq!(Direction::North, point.direction(&Point::new(2, -1)));
         assert_eq!(Direction::West, point.direction(&Point::new(-5, 2)));
         assert_eq!(Direction::East, point.direction(&Point::new(7, 2)));
+
+        // diagonal
+        assert_eq!(Direction::NorthEast, point.direction(&Point::new(3, 1)));
+        assert_eq!(Direction::SouthWest, point.direction(&Point::new(-2, 6)));
     }
 }
@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use crate::utils::point_n::PointN;
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, Mul, Sub};
 use std::str::FromStr;
@@ -31,30 +31,57 @@ impl Point3D {
         }
     }
 
-    pub fn distance(&self, other: &Self) -> f64 {
-        let diff = *self - *other;
+    /// Neighbours sharing a face
+    pub fn adjacent(&self) -> [Self; 6] {
+        let mut adjacent = [*self; 6];
+        for (slot, neighbour) in adjacent.iter_mut().zip(PointN::from(*self).neighbours()) {
+            *slot = neighbour.into();
+        }
+
+        adjacent
+    }
+
+    /// Neighbours sharing a face, an edge or a corner
+    pub fn adjacent_with_diagonals(&self) -> [Self; 26] {
+        let mut adjacent = [*self; 26];
+        let neighbours = PointN::from(*self).neighbours_with_diagonals();
+
+        for (slot, neighbour) in adjacent.iter_mut().zip(neighbours) {
+            *slot = neighbour.into();
+        }
+
+        adjacent
+    }
 
-        ((diff.x.abs().pow(2) + diff.y.abs().pow(2) + diff.z.abs().pow(2)) as f64).sqrt()
+    pub fn manhattan_distance(&self, other: &Self) -> isize {
+        PointN::from(*self).manhattan_distance(&PointN::from(*other))
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> isize {
+        PointN::from(*self).chebyshev_distance(&PointN::from(*other))
+    }
+
+    pub fn distance_squared(&self, other: &Self) -> i128 {
+        PointN::from(*self).distance_squared(&PointN::from(*other))
+    }
+
+    /// Euclidean distance, prefer `distance_squared` when comparing
+    pub fn distance(&self, other: &Self) -> f64 {
+        (self.distance_squared(other) as f64).sqrt()
     }
 }
 
 impl From<&str> for Point3D {
     fn from(value: &str) -> Self {
-        let (x, y, z) = value.split_terminator(',').collect_tuple().unwrap();
-
-        Self {
-            x: x.parse().unwrap(),
-            y: y.parse().unwrap(),
-            z: z.parse().unwrap(),
-        }
+        value.parse().unwrap()
     }
 }
 
 impl FromStr for Point3D {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::from(s))
+        s.parse::<PointN<isize, 3>>().map(Self::from)
     }
 }
 
@@ -62,7 +89,7 @@ impl Add<Point3D> for Point3D {
     type Output = Self;
 
     fn add(self, rhs: Point3D) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+        (PointN::from(self) + PointN::from(rhs)).into()
     }
 }
 
@@ -70,7 +97,7 @@ impl Mul<isize> for Point3D {
     type Output = Self;
 
     fn mul(self, rhs: isize) -> Self::Output {
-        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+        (PointN::from(self) * rhs).into()
     }
 }
 
@@ -78,19 +105,20 @@ impl Sub<Point3D> for Point3D {
     type Output = Self;
 
     fn sub(self, rhs: Point3D) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+        (PointN::from(self) - PointN::from(rhs)).into()
     }
 }
 
 impl Display for Point3D {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({},{},{})", self.x, self.y, self.z)
+        write!(f, "{}", PointN::from(*self))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use itertools::Itertools;
 
     #[test]
     fn test_distance() {
@@ -115,6 +143,15 @@ mod tests {
         assert_eq!(5.0, p1.distance(&p2));
     }
 
+    #[test]
+    fn test_adjacent() {
+        let p = Point3D::new(1, 2, 3);
+
+        assert!(p.adjacent().iter().all(|a| p.distance(a) == 1.0));
+        assert_eq!(26, p.adjacent_with_diagonals().iter().unique().count());
+        assert!(!p.adjacent_with_diagonals().contains(&p));
+    }
+
     #[test]
     fn test_distance_from_origin() {
         let p1 = Point3D::new(3, 4, 0);
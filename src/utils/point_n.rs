@@ -0,0 +1,375 @@
+use crate::utils::point::Point;
+use crate::utils::point3d::Point3D;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Signed integer usable as a coordinate of [`PointN`]
+pub trait Coordinate:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, isize);
+
+/// Point in N dimensions over any signed integer type
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PointN<T, const N: usize> {
+    coordinates: [T; N],
+}
+
+impl<T, const N: usize> PointN<T, N>
+where
+    T: Coordinate,
+{
+    pub const fn new(coordinates: [T; N]) -> Self {
+        Self { coordinates }
+    }
+
+    pub fn origin() -> Self {
+        Self::new([T::ZERO; N])
+    }
+
+    /// Point with one on the given axis and zero elsewhere
+    pub fn unit(axis: usize) -> Self {
+        let mut unit = Self::origin();
+        unit[axis] = T::ONE;
+
+        unit
+    }
+
+    pub fn coordinates(&self) -> [T; N] {
+        self.coordinates
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.zip(other).map(|(a, b)| (a - b).abs()).sum()
+    }
+
+    /// Moves along every axis at once count as one step, like a king on a chessboard
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        self.zip(other)
+            .map(|(a, b)| (a - b).abs())
+            .max()
+            .unwrap_or(T::ZERO)
+    }
+
+    /// Squared euclidean distance, in i128 so it does not overflow for small coordinate types
+    pub fn distance_squared(&self, other: &Self) -> i128 {
+        self.zip(other)
+            .map(|(a, b)| (a.to_i128() - b.to_i128()).pow(2))
+            .sum()
+    }
+
+    /// Points one step away along a single axis
+    pub fn neighbours(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+
+        (0..N).flat_map(move |axis| [point - Self::unit(axis), point + Self::unit(axis)])
+    }
+
+    /// Points differing by at most one on every axis, 3^N - 1 of them
+    pub fn neighbours_with_diagonals(&self) -> impl Iterator<Item = Self> {
+        let point = *self;
+
+        (0..3usize.pow(N as u32))
+            .map(move |mut code| {
+                let mut neighbour = point;
+                for coordinate in neighbour.coordinates.iter_mut() {
+                    *coordinate = match code % 3 {
+                        0 => *coordinate - T::ONE,
+                        1 => *coordinate,
+                        _ => *coordinate + T::ONE,
+                    };
+                    code /= 3;
+                }
+
+                neighbour
+            })
+            .filter(move |neighbour| *neighbour != point)
+    }
+
+    /// Coordinates usable as indexes, None when any of them is negative
+    pub fn to_usize(self) -> Option<[usize; N]> {
+        let mut result = [0; N];
+        for (value, coordinate) in result.iter_mut().zip(self.coordinates) {
+            *value = usize::try_from(coordinate.to_i128()).ok()?;
+        }
+
+        Some(result)
+    }
+
+    fn zip<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = (T, T)> + 'a {
+        self.coordinates
+            .iter()
+            .copied()
+            .zip(other.coordinates.iter().copied())
+    }
+
+    fn combine<F>(self, other: Self, func: F) -> Self
+    where
+        F: Fn(T, T) -> T,
+    {
+        let mut result = self;
+        for (value, other) in result.coordinates.iter_mut().zip(other.coordinates) {
+            *value = func(*value, other);
+        }
+
+        result
+    }
+}
+
+impl<T, const N: usize> Default for PointN<T, N>
+where
+    T: Coordinate,
+{
+    fn default() -> Self {
+        Self::origin()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for PointN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.coordinates[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for PointN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.coordinates[axis]
+    }
+}
+
+impl<T: Coordinate, const N: usize> Add for PointN<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a + b)
+    }
+}
+
+impl<T: Coordinate, const N: usize> Sub for PointN<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a - b)
+    }
+}
+
+impl<T: Coordinate, const N: usize> AddAssign for PointN<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate, const N: usize> SubAssign for PointN<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coordinate, const N: usize> Mul<T> for PointN<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.coordinates.map(|value| value * rhs))
+    }
+}
+
+impl<T: Coordinate, const N: usize> Neg for PointN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.coordinates.map(|value| -value))
+    }
+}
+
+impl<T: Coordinate, const N: usize> FromStr for PointN<T, N> {
+    type Err = String;
+
+    /// Comma separated coordinates, whitespace around them is ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = Self::origin();
+        let mut parts = s.split(',');
+
+        for coordinate in result.coordinates.iter_mut() {
+            let part = parts
+                .next()
+                .ok_or(format!("Expected {} coordinates in '{}'", N, s))?;
+
+            *coordinate = part
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid coordinate '{}' in '{}'", part.trim(), s))?;
+        }
+
+        if parts.next().is_some() {
+            return Err(format!("Expected {} coordinates in '{}'", N, s));
+        }
+
+        Ok(result)
+    }
+}
+
+impl<T: Display, const N: usize> Display for PointN<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let coordinates: Vec<String> = self.coordinates.iter().map(|c| c.to_string()).collect();
+
+        write!(f, "({})", coordinates.join(","))
+    }
+}
+
+impl From<Point> for PointN<isize, 2> {
+    fn from(point: Point) -> Self {
+        Self::new([point.x, point.y])
+    }
+}
+
+impl From<PointN<isize, 2>> for Point {
+    fn from(point: PointN<isize, 2>) -> Self {
+        Self::new(point[0], point[1])
+    }
+}
+
+impl From<Point3D> for PointN<isize, 3> {
+    fn from(point: Point3D) -> Self {
+        Self::new([point.x, point.y, point.z])
+    }
+}
+
+impl From<PointN<isize, 3>> for Point3D {
+    fn from(point: PointN<isize, 3>) -> Self {
+        Self::new(point[0], point[1], point[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::point::Point;
+    use crate::utils::point3d::Point3D;
+    use crate::utils::point_n::PointN;
+    use itertools::Itertools;
+
+    #[test]
+    fn arithmetic() {
+        let a = PointN::new([1i8, -2, 3]);
+        let b = PointN::new([4i8, 5, -6]);
+
+        assert_eq!(PointN::new([5, 3, -3]), a + b);
+        assert_eq!(PointN::new([-3, -7, 9]), a - b);
+        assert_eq!(PointN::new([2, -4, 6]), a * 2);
+        assert_eq!(PointN::new([-1, 2, -3]), -a);
+        assert_eq!(PointN::new([0, 0, 0]), PointN::<i8, 3>::default());
+        assert_eq!(-2, a[1]);
+    }
+
+    #[test]
+    fn metrics() {
+        let a = PointN::new([1i32, 2, 3, 4]);
+        let b = PointN::new([4i32, -2, 3, 5]);
+
+        assert_eq!(8, a.manhattan_distance(&b));
+        assert_eq!(4, a.chebyshev_distance(&b));
+        assert_eq!(26, a.distance_squared(&b));
+
+        let far = PointN::new([i16::MAX, 0]);
+        assert_eq!(
+            (i16::MAX as i128 + 1).pow(2),
+            far.distance_squared(&PointN::new([-1, 0]))
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let point = PointN::new([0i64, 0, 0]);
+
+        assert_eq!(6, point.neighbours().count());
+        assert!(point
+            .neighbours()
+            .all(|n| n.manhattan_distance(&point) == 1));
+        assert_eq!(26, point.neighbours_with_diagonals().unique().count());
+        assert!(point
+            .neighbours_with_diagonals()
+            .all(|n| n.chebyshev_distance(&point) == 1));
+        assert_eq!(
+            80,
+            PointN::new([0i8; 4]).neighbours_with_diagonals().count()
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let point: PointN<i32, 3> = " 1, -20,300".parse().unwrap();
+
+        assert_eq!(PointN::new([1, -20, 300]), point);
+        assert_eq!("(1,-20,300)", point.to_string());
+        assert_eq!(
+            Err("Expected 3 coordinates in '1,2'".to_string()),
+            "1,2".parse::<PointN<i32, 3>>()
+        );
+        assert_eq!(
+            Err("Expected 2 coordinates in '1,2,3'".to_string()),
+            "1,2,3".parse::<PointN<i32, 2>>()
+        );
+        assert_eq!(
+            Err("Invalid coordinate 'x' in '1,x'".to_string()),
+            "1,x".parse::<PointN<i32, 2>>()
+        );
+        assert!("300,1".parse::<PointN<i8, 2>>().is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Some([3, 4]), PointN::new([3i16, 4]).to_usize());
+        assert_eq!(None, PointN::new([3i16, -4]).to_usize());
+
+        let point = Point::new(5, -6);
+        assert_eq!(point, Point::from(PointN::from(point)));
+
+        let point3d = Point3D::new(1, 2, 3);
+        assert_eq!(point3d, Point3D::from(PointN::from(point3d)));
+
+        assert_eq!("(5,-6)", point.to_string());
+        assert_eq!(PointN::from(point3d).to_string(), point3d.to_string());
+    }
+}
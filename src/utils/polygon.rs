@@ -1,5 +1,7 @@
+use crate::utils::coordinate_compression::CoordinateCompression;
 use crate::utils::grid_line::GridLine;
 use crate::utils::point::Point;
+use crate::utils::surface_range::SurfaceRange;
 use crate::utils::traits::{Intersect, IsInside};
 use itertools::Itertools;
 
@@ -24,6 +26,7 @@ impl Polygon {
             .collect()
     }
 
+    #[allow(dead_code)]
     pub fn rectangle(first_corner: Point, second_corner: Point) -> Self {
         let points = [
             Point::new(first_corner.x, first_corner.y),
@@ -35,6 +38,49 @@ impl Polygon {
         Self::from_iter(points)
     }
 
+    /// Corners in the order they were given
+    pub fn vertices(&self) -> Vec<Point> {
+        self.lines.iter().map(GridLine::start).collect()
+    }
+
+    /// Geometric area enclosed by the lines, from the shoelace formula
+    pub fn area(&self) -> isize {
+        let doubled: isize = self
+            .lines
+            .iter()
+            .map(|line| {
+                let (a, b) = (line.start(), line.end());
+
+                a.x * b.y - b.x * a.y
+            })
+            .sum();
+
+        doubled.abs() / 2
+    }
+
+    /// Number of lattice points lying on the lines
+    pub fn boundary_points(&self) -> isize {
+        self.lines
+            .iter()
+            .map(|line| line.start().manhattan_distance(&line.end()))
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside, from Pick's theorem
+    pub fn interior_points(&self) -> isize {
+        self.area() - self.boundary_points() / 2 + 1
+    }
+
+    /// Number of lattice points inside or on the boundary, like grid cells covered by the polygon
+    pub fn lattice_points(&self) -> isize {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Table answering in constant time whether a rectangle is fully inside
+    pub fn mask(&self) -> PolygonMask {
+        PolygonMask::new(self)
+    }
+
     #[allow(dead_code)]
     pub fn extend(&self) -> Self {
         let lines = self
@@ -131,12 +177,111 @@ impl Intersect<GridLine> for Polygon {
     }
 }
 
+/// Polygon on a grid compressed by every vertex coordinate, each cell is fully inside or fully outside.
+/// A 2D prefix sum of the outside cells makes rectangle queries constant time.
+pub struct PolygonMask {
+    compression: CoordinateCompression,
+    outside: Vec<usize>,
+}
+
+impl PolygonMask {
+    fn new(polygon: &Polygon) -> Self {
+        let compression = CoordinateCompression::from_points(&polygon.vertices());
+        let (width, height) = (compression.width(), compression.height());
+        let mut inside = compression.grid(false);
+
+        for line in &polygon.lines {
+            let (start, end) = (
+                compression.compress(&line.start()).unwrap(),
+                compression.compress(&line.end()).unwrap(),
+            );
+
+            for point in SurfaceRange::from_points(start.x, end.x, start.y, end.y).points() {
+                inside
+                    .get_mut(point.x as usize, point.y as usize)
+                    .unwrap()
+                    .value = true;
+            }
+        }
+
+        for row in 0..height {
+            let y = compression.y().span(row).start();
+            let crossings: Vec<isize> = polygon
+                .lines
+                .iter()
+                .filter(|line| line.is_vertical())
+                .filter(|line| {
+                    let (a, b) = (line.start().y, line.end().y);
+
+                    a.min(b) <= y && y < a.max(b)
+                })
+                .map(|line| line.start().x)
+                .sorted()
+                .collect();
+
+            let mut passed = 0;
+            for column in 0..width {
+                let x = compression.x().span(column).start();
+                while passed < crossings.len() && crossings[passed] <= x {
+                    passed += 1;
+                }
+
+                if passed % 2 == 1 {
+                    inside.get_mut(column, row).unwrap().value = true;
+                }
+            }
+        }
+
+        let mut outside = vec![0; (width + 1) * (height + 1)];
+        for (column, row, cell) in inside.iter() {
+            outside[(row + 1) * (width + 1) + column + 1] = usize::from(!cell.value)
+                + outside[row * (width + 1) + column + 1]
+                + outside[(row + 1) * (width + 1) + column]
+                - outside[row * (width + 1) + column];
+        }
+
+        Self {
+            compression,
+            outside,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, point: &Point) -> bool {
+        self.contains_rectangle(*point, *point)
+    }
+
+    /// Whether every point of the rectangle spanned by both corners is inside or on the boundary
+    pub fn contains_rectangle(&self, first_corner: Point, second_corner: Point) -> bool {
+        let (Some(first), Some(second)) = (
+            self.compression.compress(&first_corner),
+            self.compression.compress(&second_corner),
+        ) else {
+            return false;
+        };
+
+        let width = self.compression.width() + 1;
+        let (left, right) = (
+            first.x.min(second.x) as usize,
+            first.x.max(second.x) as usize + 1,
+        );
+        let (top, bottom) = (
+            first.y.min(second.y) as usize,
+            first.y.max(second.y) as usize + 1,
+        );
+
+        let at = |row: usize, column: usize| self.outside[row * width + column];
+
+        at(bottom, right) + at(top, left) - at(top, right) - at(bottom, left) == 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::utils::grid_line::GridLine;
     use crate::utils::point::Point;
     use crate::utils::polygon::Polygon;
-    use crate::utils::traits::Intersect;
+    use crate::utils::traits::{Intersect, IsInside};
 
     #[test]
     fn from_iterator() {
@@ -408,4 +553,59 @@ mod tests {
         assert!(!l_shape.intersect(&separate));
         assert!(!separate.intersect(&l_shape));
     }
+
+    fn u_shape() -> Polygon {
+        Polygon::from_iter([
+            Point::new(0, 0),
+            Point::new(6, 0),
+            Point::new(6, 4),
+            Point::new(4, 4),
+            Point::new(4, 2),
+            Point::new(2, 2),
+            Point::new(2, 4),
+            Point::new(0, 4),
+        ])
+    }
+
+    #[test]
+    fn area_and_lattice_points() {
+        let square = Polygon::rectangle(Point::new(0, 0), Point::new(4, 4));
+
+        assert_eq!(16, square.area());
+        assert_eq!(16, square.boundary_points());
+        assert_eq!(9, square.interior_points());
+        assert_eq!(25, square.lattice_points());
+
+        let u = u_shape();
+        assert_eq!(20, u.area());
+        assert_eq!(24, u.boundary_points());
+        assert_eq!(9, u.interior_points());
+        assert_eq!(33, u.lattice_points());
+    }
+
+    #[test]
+    fn mask_matches_is_inside() {
+        let u = u_shape();
+        let mask = u.mask();
+
+        for x in -2..=8 {
+            for y in -2..=6 {
+                let point = Point::new(x, y);
+
+                assert_eq!(u.is_inside(&point), mask.contains(&point), "{}", point);
+            }
+        }
+    }
+
+    #[test]
+    fn mask_contains_rectangle() {
+        let mask = u_shape().mask();
+
+        assert!(mask.contains_rectangle(Point::new(0, 0), Point::new(6, 2)));
+        assert!(mask.contains_rectangle(Point::new(2, 4), Point::new(0, 0)));
+        assert!(!mask.contains_rectangle(Point::new(0, 0), Point::new(6, 3)));
+        assert!(!mask.contains_rectangle(Point::new(1, 1), Point::new(3, 3)));
+        assert!(!mask.contains_rectangle(Point::new(-1, 0), Point::new(2, 2)));
+        assert!(!mask.contains_rectangle(Point::new(5, 0), Point::new(7, 1)));
+    }
 }
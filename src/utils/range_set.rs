@@ -0,0 +1,364 @@
+use crate::utils::range::Range;
+use std::fmt;
+use std::fmt::Display;
+
+/// Union of ranges kept as a sorted list of disjoint ranges.
+/// Overlapping and touching ranges are merged, so every set has exactly one representation.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RangeSet {
+    ranges: Vec<Range>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, range: Range) {
+        let from = self
+            .ranges
+            .partition_point(|r| r.end() < range.start().saturating_sub(1));
+        let to = self
+            .ranges
+            .partition_point(|r| r.start() <= range.end().saturating_add(1));
+
+        let merged = self.ranges[from..to].iter().fold(range, |merged, r| {
+            Range::new(merged.start().min(r.start()), merged.end().max(r.end())).unwrap()
+        });
+
+        self.ranges.splice(from..to, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+
+            if let Ok(common) = a.intersect(&b) {
+                ranges.push(common);
+            }
+
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match self.bounds() {
+            Some(bounds) => self.intersection(&other.complement(bounds)),
+            None => Self::new(),
+        }
+    }
+
+    /// Values of the bound which are not in the set
+    pub fn complement(&self, bound: Range) -> Self {
+        let mut ranges = Vec::new();
+        let mut start = bound.start();
+
+        for range in self.ranges.iter().filter(|r| r.collide(&bound)) {
+            if range.start() > start {
+                ranges.push(Range::new(start, range.start() - 1).unwrap());
+            }
+
+            // nothing can follow a range ending at the largest value
+            let Some(next) = range.end().checked_add(1) else {
+                return Self { ranges };
+            };
+            start = next;
+        }
+
+        if start <= bound.end() {
+            ranges.push(Range::new(start, bound.end()).unwrap());
+        }
+
+        Self { ranges }
+    }
+
+    /// Every range moved by the given offset, None if a value would leave `isize`
+    pub fn shift(&self, by: isize) -> Option<Self> {
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|r| Range::new(r.start().checked_add(by)?, r.end().checked_add(by)?).ok())
+            .collect::<Option<Vec<Range>>>()?;
+
+        Some(Self { ranges })
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> isize {
+        self.ranges.iter().map(Range::len).sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        let i = self.ranges.partition_point(|r| r.end() < value);
+
+        self.ranges.get(i).is_some_and(|r| r.contains(value))
+    }
+
+    /// From the lowest to the highest value, None for an empty set
+    pub fn bounds(&self) -> Option<Range> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+
+        Some(Range::new(first.start(), last.end()).unwrap())
+    }
+
+    #[allow(dead_code)]
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = &Range> {
+        self.ranges.iter()
+    }
+
+    /// All values in ascending order
+    #[allow(dead_code)]
+    pub fn values(&self) -> impl Iterator<Item = isize> + '_ {
+        self.ranges.iter().flat_map(|r| r.iter())
+    }
+}
+
+impl From<Range> for RangeSet {
+    fn from(range: Range) -> Self {
+        Self {
+            ranges: vec![range],
+        }
+    }
+}
+
+impl FromIterator<Range> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range>>(iter: I) -> Self {
+        let mut sorted: Vec<Range> = iter.into_iter().collect();
+        sorted.sort_by_key(|r| r.start());
+
+        let mut ranges: Vec<Range> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last)
+                    if last
+                        .end()
+                        .checked_add(1)
+                        .is_none_or(|next| range.start() <= next) =>
+                {
+                    *last = Range::new(last.start(), last.end().max(range.end())).unwrap();
+                }
+                _ => ranges.push(range),
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl Extend<Range> for RangeSet {
+    fn extend<I: IntoIterator<Item = Range>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl Display for RangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(|r| r.to_string()).collect();
+
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::range::Range;
+    use crate::utils::range_set::RangeSet;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(ranges: &[(isize, isize)]) -> RangeSet {
+        ranges
+            .iter()
+            .map(|&(a, b)| Range::new(a, b).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching() {
+        let ranges = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (6, 6)]);
+
+        assert_eq!(set(&[(3, 6), (10, 20)]), ranges);
+        assert_eq!(15, ranges.len());
+        assert_eq!("{(3, 6), (10, 20)}", ranges.to_string());
+    }
+
+    #[test]
+    fn insert() {
+        let mut ranges = set(&[(1, 2), (5, 6), (10, 12)]);
+
+        ranges.insert(Range::new(3, 4).unwrap());
+        assert_eq!(set(&[(1, 6), (10, 12)]), ranges);
+
+        ranges.insert(Range::new(14, 15).unwrap());
+        assert_eq!(set(&[(1, 6), (10, 12), (14, 15)]), ranges);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (20, 21)]);
+
+        assert_eq!(set(&[(1, 15), (20, 21)]), a.union(&b));
+        assert_eq!(set(&[(4, 5), (10, 11)]), a.intersection(&b));
+        assert_eq!(set(&[(1, 3), (12, 15)]), a.difference(&b));
+        assert_eq!(
+            set(&[(0, 0), (6, 9), (16, 16)]),
+            a.complement(Range::new(0, 16).unwrap())
+        );
+        assert_eq!(Some(set(&[(-1, 3), (8, 13)])), a.shift(-2));
+    }
+
+    #[test]
+    fn extreme_values() {
+        let mut ranges = set(&[(isize::MIN, isize::MIN + 1), (isize::MAX - 1, isize::MAX)]);
+        ranges.insert(Range::new(isize::MIN, 0).unwrap());
+        ranges.insert(Range::new(5, isize::MAX).unwrap());
+
+        assert_eq!(set(&[(isize::MIN, 0), (5, isize::MAX)]), ranges);
+        assert_eq!(
+            set(&[(1, 4)]),
+            ranges.complement(Range::new(isize::MIN, isize::MAX).unwrap())
+        );
+
+        let everything = set(&[(0, isize::MAX), (5, 10), (isize::MIN, -1)]);
+        assert_eq!(set(&[(isize::MIN, isize::MAX)]), everything);
+        assert_eq!(
+            set(&[(isize::MIN, 0), (2, isize::MAX)]),
+            ranges.union(&set(&[(isize::MAX, isize::MAX), (2, 4)]))
+        );
+        assert_eq!(None, ranges.shift(1));
+        assert_eq!(None, ranges.shift(-1));
+        assert_eq!(
+            Some(set(&[(isize::MIN + 1, 1), (6, isize::MAX)])),
+            set(&[(isize::MIN, 0), (5, isize::MAX - 1)]).shift(1)
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let ranges = set(&[(1, 5), (10, 15)]);
+
+        assert!(!ranges.contains(0));
+        assert!(ranges.contains(1));
+        assert!(ranges.contains(5));
+        assert!(!ranges.contains(7));
+        assert!(ranges.contains(15));
+        assert!(!ranges.contains(16));
+        assert!(!RangeSet::new().contains(0));
+    }
+
+    const MIN: isize = -20;
+    const MAX: isize = 20;
+
+    fn ranges() -> impl Strategy<Value = Vec<Range>> {
+        prop::collection::vec((MIN..=MAX, 0..8isize), 0..6).prop_map(|ranges| {
+            ranges
+                .into_iter()
+                .map(|(start, len)| Range::new(start, (start + len).min(MAX)).unwrap())
+                .collect()
+        })
+    }
+
+    fn model(ranges: &[Range]) -> BTreeSet<isize> {
+        ranges.iter().flat_map(|r| r.iter()).collect()
+    }
+
+    fn values(set: &RangeSet) -> BTreeSet<isize> {
+        set.values().collect()
+    }
+
+    fn is_normalised(set: &RangeSet) -> bool {
+        set.ranges()
+            .windows(2)
+            .all(|pair| pair[0].end() + 1 < pair[1].start())
+    }
+
+    proptest! {
+        #[test]
+        fn behaves_like_a_set_of_values(a in ranges(), b in ranges()) {
+            let (set_a, set_b): (RangeSet, RangeSet) =
+                (a.iter().copied().collect(), b.iter().copied().collect());
+            let (model_a, model_b) = (model(&a), model(&b));
+
+            prop_assert!(is_normalised(&set_a));
+            prop_assert_eq!(&model_a, &values(&set_a));
+            prop_assert_eq!(model_a.len() as isize, set_a.len());
+
+            let union = set_a.union(&set_b);
+            prop_assert!(is_normalised(&union));
+            prop_assert_eq!(model_a.union(&model_b).copied().collect::<BTreeSet<_>>(), values(&union));
+
+            let intersection = set_a.intersection(&set_b);
+            prop_assert!(is_normalised(&intersection));
+            prop_assert_eq!(
+                model_a.intersection(&model_b).copied().collect::<BTreeSet<_>>(),
+                values(&intersection)
+            );
+
+            let difference = set_a.difference(&set_b);
+            prop_assert!(is_normalised(&difference));
+            prop_assert_eq!(
+                model_a.difference(&model_b).copied().collect::<BTreeSet<_>>(),
+                values(&difference)
+            );
+        }
+
+        #[test]
+        fn insert_matches_collect(a in ranges()) {
+            let mut inserted = RangeSet::new();
+            inserted.extend(a.iter().copied());
+
+            prop_assert_eq!(a.iter().copied().collect::<RangeSet>(), inserted);
+        }
+
+        #[test]
+        fn complement_contains_and_shift(a in ranges(), start in MIN..0isize, value in MIN..=MAX, by in -5..5isize) {
+            let set: RangeSet = a.iter().copied().collect();
+            let model = model(&a);
+            let bound = Range::new(start, start + 25).unwrap();
+
+            let complement = set.complement(bound);
+            prop_assert!(is_normalised(&complement));
+            prop_assert_eq!(
+                bound.iter().filter(|v| !model.contains(v)).collect::<BTreeSet<_>>(),
+                values(&complement)
+            );
+
+            prop_assert_eq!(model.contains(&value), set.contains(value));
+            prop_assert_eq!(
+                model.iter().map(|v| v + by).collect::<BTreeSet<_>>(),
+                values(&set.shift(by).unwrap())
+            );
+        }
+    }
+}
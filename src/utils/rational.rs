@@ -0,0 +1,241 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Exact fraction over i128, always reduced and with a positive denominator
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    pub const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert_ne!(0, denominator, "Denominator cannot be zero");
+
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    #[allow(dead_code)]
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Some only when there is no fractional part
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    #[allow(dead_code)]
+    pub fn floor(&self) -> i128 {
+        self.numerator.div_euclid(self.denominator)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+
+    #[allow(dead_code)]
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    #[allow(dead_code)]
+    pub fn abs(&self) -> Self {
+        Self {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn recip(&self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a.max(1)
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(value: isize) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let divisor = gcd(self.denominator, rhs.denominator);
+        let lhs_factor = rhs.denominator / divisor;
+        let rhs_factor = self.denominator / divisor;
+
+        Self::new(
+            self.numerator * lhs_factor + rhs.numerator * rhs_factor,
+            self.denominator * lhs_factor,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    /// Cross reduces before multiplying, so results which fit do not overflow on the way
+    fn mul(self, rhs: Self) -> Self::Output {
+        let a = gcd(self.numerator, rhs.denominator);
+        let b = gcd(rhs.numerator, self.denominator);
+
+        Self::new(
+            (self.numerator / a) * (rhs.numerator / b),
+            (self.denominator / b) * (rhs.denominator / a),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let a = gcd(self.numerator, rhs.numerator);
+        let b = gcd(self.denominator, rhs.denominator);
+
+        Self::new(
+            (self.numerator / a) * (rhs.denominator / b),
+            (self.denominator / b) * (rhs.numerator / a),
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self - *other).numerator.cmp(&0)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            return write!(f, "{}", self.numerator);
+        }
+
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::rational::Rational;
+
+    #[test]
+    fn normalises() {
+        assert_eq!(Rational::new(1, 2), Rational::new(-3, -6));
+        assert_eq!(Rational::new(-2, 3), Rational::new(4, -6));
+        assert_eq!(Rational::ZERO, Rational::new(0, -5));
+        assert_eq!("-2/3", Rational::new(4, -6).to_string());
+        assert_eq!("7", Rational::new(14, 2).to_string());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+
+        assert_eq!(Rational::new(5, 6), half + third);
+        assert_eq!(Rational::new(1, 6), half - third);
+        assert_eq!(Rational::new(1, 6), half * third);
+        assert_eq!(Rational::new(3, 2), half / third);
+        assert_eq!(Some(1), (half + half).to_integer());
+        assert_eq!(None, half.to_integer());
+        assert_eq!(-1, (-half).floor());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+        assert!(
+            Rational::from(400_000_000_000_000isize) > Rational::new(399_999_999_999_999_999, 1000)
+        );
+    }
+
+    #[test]
+    fn large_values_stay_exact() {
+        let big = Rational::new(200_000_000_000_000 * 1_000_000_007, 1_000_000_007);
+        let product = big * Rational::new(3, 200_000_000_000_000);
+
+        assert_eq!(Some(200_000_000_000_000), big.to_integer());
+        assert_eq!(Rational::from(3i128), product);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::utils::grid_like::GridLike;
+use crate::utils::point::Point;
+use crate::utils::render::Colour;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+const RESET: &str = "\x1b[0m";
+
+type Palette<'a, C> = Box<dyn Fn(&C) -> Colour + 'a>;
+
+/// Prints grid cells in 256-colour terminal escapes.
+/// Cells are coloured by the palette, highlighted points get a background colour,
+/// the later highlight wins when they overlap.
+pub struct AnsiRenderer<'a, G>
+where
+    G: GridLike,
+{
+    grid: &'a G,
+    palette: Palette<'a, G::Cell>,
+    highlights: Vec<(HashSet<Point>, Colour)>,
+}
+
+impl<'a, G> AnsiRenderer<'a, G>
+where
+    G: GridLike,
+    G::Cell: Display,
+{
+    pub fn new(grid: &'a G, palette: Palette<'a, G::Cell>) -> Self {
+        Self {
+            grid,
+            palette,
+            highlights: Vec::new(),
+        }
+    }
+
+    pub fn highlight<I>(mut self, points: I, colour: Colour) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        self.highlights.push((points.into_iter().collect(), colour));
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn highlight_path(self, path: &[Point], colour: Colour) -> Self {
+        self.highlight(path.iter().copied(), colour)
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let Some(surface) = self.grid.surface() else {
+            return out;
+        };
+
+        for y in surface.rows().iter() {
+            for x in surface.columns().iter() {
+                let point = Point::new(x, y);
+
+                if let Some(colour) = self.background(&point) {
+                    out.push_str(&format!("\x1b[48;5;{}m", colour.to_ansi256()));
+                }
+
+                match self.grid.get_for_point(&point) {
+                    Some(cell) => out.push_str(&format!(
+                        "\x1b[38;5;{}m{}",
+                        (self.palette)(cell).to_ansi256(),
+                        cell
+                    )),
+                    None => out.push(' '),
+                }
+
+                out.push_str(RESET);
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    #[allow(dead_code)]
+    pub fn print(&self) {
+        print!("{}", self.render());
+    }
+
+    fn background(&self, point: &Point) -> Option<Colour> {
+        self.highlights
+            .iter()
+            .rev()
+            .find(|(points, _)| points.contains(point))
+            .map(|(_, colour)| *colour)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::Grid;
+    use crate::utils::point::Point;
+    use crate::utils::render::ansi::AnsiRenderer;
+    use crate::utils::render::Colour;
+
+    #[test]
+    fn render() {
+        let grid: Grid<char> = Grid::from("#.\n.#");
+        let renderer = AnsiRenderer::new(
+            &grid,
+            Box::new(|c| match c {
+                '#' => Colour::RED,
+                _ => Colour::WHITE,
+            }),
+        );
+
+        assert_eq!(
+            "\x1b[38;5;196m#\x1b[0m\x1b[38;5;231m.\x1b[0m\n\
+             \x1b[38;5;231m.\x1b[0m\x1b[38;5;196m#\x1b[0m\n",
+            renderer.render()
+        );
+    }
+
+    #[test]
+    fn highlights() {
+        let grid: Grid<char> = Grid::from("...");
+        let renderer = AnsiRenderer::new(&grid, Box::new(|_| Colour::WHITE))
+            .highlight_path(&[Point::new(0, 0), Point::new(1, 0)], Colour::BLUE)
+            .highlight([Point::new(1, 0)], Colour::GREEN);
+
+        let rendered = renderer.render();
+        let cells: Vec<&str> = rendered.trim_end().split("\x1b[0m").collect();
+
+        assert!(cells[0].starts_with("\x1b[48;5;21m"));
+        assert!(cells[1].starts_with("\x1b[48;5;46m"));
+        assert!(!cells[2].contains("48;5"));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::utils::grid::Grid;
+use crate::utils::grid_like::GridLike;
+use crate::utils::point::Point;
+use crate::utils::render::ansi::AnsiRenderer;
+use crate::utils::render::image::Image;
+use crate::utils::render::Colour;
+use crate::utils::surface_range::SurfaceRange;
+use std::collections::{HashSet, VecDeque};
+
+/// Single snapshot of a simulation, drawn with characters like the puzzle input
+#[derive(Debug, Clone)]
+pub struct Frame {
+    grid: Grid<char>,
+    highlights: Vec<(HashSet<Point>, Colour)>,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>) -> Self {
+        Self {
+            grid,
+            highlights: Vec::new(),
+        }
+    }
+
+    pub fn from_grid<G, F>(grid: &G, to_char: F) -> Self
+    where
+        G: GridLike,
+        F: Fn(&G::Cell) -> char,
+    {
+        Self::new(grid.cells().map(|(p, cell)| (p, to_char(cell))).collect())
+    }
+
+    /// Points are drawn as `#` on `.` background covering the surface
+    pub fn from_points(points: &HashSet<Point>, surface: SurfaceRange) -> Self {
+        let mut grid = Grid::filled(surface, '.');
+        grid.modify_many(
+            points
+                .iter()
+                .filter(|p| surface.contains(**p))
+                .copied()
+                .collect(),
+            '#',
+        );
+
+        Self::new(grid)
+    }
+
+    /// Later highlights are drawn over earlier ones
+    pub fn highlight<I>(mut self, points: I, colour: Colour) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        self.highlights.push((points.into_iter().collect(), colour));
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    pub fn to_ansi(&self) -> String {
+        self.highlights
+            .iter()
+            .fold(
+                AnsiRenderer::new(&self.grid, Box::new(palette)),
+                |renderer, (points, colour)| renderer.highlight(points.iter().copied(), *colour),
+            )
+            .render()
+    }
+
+    pub fn to_image(&self, scale: usize) -> Image {
+        let highlighted: Grid<Colour> = self
+            .grid
+            .cells()
+            .map(|(p, c)| {
+                let colour = self
+                    .highlights
+                    .iter()
+                    .rev()
+                    .find(|(points, _)| points.contains(&p))
+                    .map_or_else(|| palette(c), |(_, colour)| *colour);
+
+                (p, colour)
+            })
+            .collect();
+
+        Image::from_grid(&highlighted, |c| *c, scale)
+    }
+}
+
+/// Colours of characters used in puzzle inputs: empty space is black, walls grey,
+/// digits a gradient and every other character a colour of its own
+pub fn palette(c: &char) -> Colour {
+    match c {
+        '.' | ' ' => Colour::BLACK,
+        '#' => Colour::GREY,
+        '0'..='9' => Colour::gradient(c.to_digit(10).unwrap() as usize, 9),
+        _ => {
+            let code = *c as u32;
+            let level = |i: u32| (80 + (code * i) % 176) as u8;
+
+            Colour::new(level(37), level(101), level(59))
+        }
+    }
+}
+
+/// Receiver of simulation frames, solutions push into it while they compute the answer
+pub trait FrameSink {
+    /// Frame is built lazily, so pushing into a disabled sink costs nothing
+    fn push(&mut self, frame: &dyn Fn() -> Frame);
+}
+
+/// Sink used during normal solving
+pub struct NoFrames;
+
+impl FrameSink for NoFrames {
+    fn push(&mut self, _frame: &dyn Fn() -> Frame) {}
+}
+
+/// Keeps pushed frames, with a limit only the latest ones are kept
+pub struct FrameRecorder {
+    frames: VecDeque<Frame>,
+    limit: Option<usize>,
+}
+
+impl FrameRecorder {
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            frames: VecDeque::new(),
+            limit,
+        }
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames.into()
+    }
+}
+
+impl FrameSink for FrameRecorder {
+    fn push(&mut self, frame: &dyn Fn() -> Frame) {
+        if self.limit == Some(0) {
+            return;
+        }
+
+        if self.limit.is_some_and(|limit| self.frames.len() == limit) {
+            self.frames.pop_front();
+        }
+
+        self.frames.push_back(frame());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::Grid;
+    use crate::utils::point::Point;
+    use crate::utils::render::frames::{palette, Frame, FrameRecorder, FrameSink, NoFrames};
+    use crate::utils::render::Colour;
+    use crate::utils::surface_range::SurfaceRange;
+    use std::cell::Cell;
+    use std::collections::HashSet;
+
+    #[test]
+    fn from_points() {
+        let points = HashSet::from([Point::new(1, 0), Point::new(5, 5)]);
+
+        let frame = Frame::from_points(&points, SurfaceRange::from_points(0, 2, 0, 1));
+
+        assert_eq!(".#.\n...\n", frame.grid().to_string());
+    }
+
+    #[test]
+    fn to_image_with_highlight() {
+        let frame = Frame::new(Grid::from("#.")).highlight([Point::new(1, 0)], Colour::YELLOW);
+
+        let image = frame.to_image(1);
+
+        assert_eq!(Some(palette(&'#')), image.get(0, 0));
+        assert_eq!(Some(Colour::YELLOW), image.get(1, 0));
+    }
+
+    #[test]
+    fn to_ansi() {
+        let frame = Frame::new(Grid::from("#")).highlight([Point::new(0, 0)], Colour::RED);
+
+        assert!(frame.to_ansi().starts_with("\x1b[48;5;196m"));
+    }
+
+    #[test]
+    fn recorder_keeps_latest_frames() {
+        let mut recorder = FrameRecorder::new(Some(2));
+
+        for c in ['A', 'B', 'C'] {
+            recorder.push(&|| Frame::new(Grid::from(c.to_string().as_str())));
+        }
+
+        let frames: Vec<String> = recorder
+            .into_frames()
+            .iter()
+            .map(|f| f.grid().to_string())
+            .collect();
+        assert_eq!(vec!["B\n", "C\n"], frames);
+    }
+
+    #[test]
+    fn no_frames_is_lazy() {
+        let built = Cell::new(false);
+
+        NoFrames.push(&|| {
+            built.set(true);
+            Frame::new(Grid::from("#"))
+        });
+
+        assert!(!built.get());
+    }
+}
@@ -0,0 +1,151 @@
+//! Minimal animated GIF encoder using the 216 colours of the terminal colour cube
+
+use crate::utils::render::image::Image;
+use crate::utils::render::Colour;
+
+const MIN_CODE_SIZE: u8 = 8;
+const CLEAR_CODE: u16 = 256;
+const END_CODE: u16 = 257;
+/// Codes emitted between clear codes, small enough to keep every code 9 bits long
+const CODES_PER_CLEAR: usize = 250;
+
+/// All frames share the canvas of the biggest one, `delay` is in hundredths of a second
+pub fn encode(frames: &[Image], delay: u16) -> Vec<u8> {
+    let width = frames.iter().map(|f| f.width()).max().unwrap_or(1);
+    let height = frames.iter().map(|f| f.height()).max().unwrap_or(1);
+
+    let mut gif: Vec<u8> = b"GIF89a".to_vec();
+    gif.extend((width as u16).to_le_bytes());
+    gif.extend((height as u16).to_le_bytes());
+    // global colour table with 256 entries
+    gif.extend([0xf7, 0, 0]);
+    gif.extend(colour_table());
+    // loop forever
+    gif.extend([0x21, 0xff, 0x0b]);
+    gif.extend(b"NETSCAPE2.0");
+    gif.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    for frame in frames {
+        gif.extend([0x21, 0xf9, 0x04, 0x00]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0x00, 0x00]);
+
+        gif.push(0x2c);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend((width as u16).to_le_bytes());
+        gif.extend((height as u16).to_le_bytes());
+        gif.push(0x00);
+
+        let indices: Vec<u8> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| colour_index(frame.get(x, y).unwrap_or(Colour::BLACK)))
+            .collect();
+
+        gif.push(MIN_CODE_SIZE);
+        for block in lzw(&indices).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0x00);
+    }
+
+    gif.push(0x3b);
+
+    gif
+}
+
+fn colour_table() -> Vec<u8> {
+    let levels = |i: usize| (i * 255 / 5) as u8;
+
+    (0..256)
+        .flat_map(|i| {
+            if i < 216 {
+                [levels(i / 36), levels(i / 6 % 6), levels(i % 6)]
+            } else {
+                [0, 0, 0]
+            }
+        })
+        .collect()
+}
+
+fn colour_index(colour: Colour) -> u8 {
+    colour.to_ansi256() - 16
+}
+
+/// Every pixel is written as a literal code, which is valid LZW without a dictionary
+fn lzw(indices: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter::default();
+
+    for chunk in indices.chunks(CODES_PER_CLEAR) {
+        writer.write(CLEAR_CODE, 9);
+        for index in chunk {
+            writer.write(*index as u16, 9);
+        }
+    }
+    writer.write(END_CODE, 9);
+
+    writer.finish()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::render::gif::{colour_index, encode, lzw};
+    use crate::utils::render::image::Image;
+    use crate::utils::render::Colour;
+
+    #[test]
+    fn colour_indices() {
+        assert_eq!(0, colour_index(Colour::BLACK));
+        assert_eq!(215, colour_index(Colour::WHITE));
+        assert_eq!(180, colour_index(Colour::RED));
+    }
+
+    #[test]
+    fn lzw_codes() {
+        // clear (256), 1, end (257) as 9-bit codes, least significant bit first
+        assert_eq!(vec![0x00, 0x03, 0x04, 0x04], lzw(&[1]));
+    }
+
+    #[test]
+    fn encode_frames() {
+        let frames = [
+            Image::filled(2, 1, Colour::RED),
+            Image::filled(1, 3, Colour::BLUE),
+        ];
+
+        let gif = encode(&frames, 10);
+
+        assert_eq!(b"GIF89a", &gif[..6]);
+        assert_eq!([2, 0, 3, 0], gif[6..10]);
+        assert_eq!(2, gif.windows(2).filter(|w| w == &[0x21, 0xf9]).count());
+        assert_eq!(Some(&0x3b), gif.last());
+    }
+}
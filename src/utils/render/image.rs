@@ -0,0 +1,225 @@
+use crate::utils::grid_like::GridLike;
+use crate::utils::point::Point;
+use crate::utils::render::{png, Colour};
+use crate::utils::surface_range::SurfaceRange;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// RGB raster where every grid cell becomes a `scale` x `scale` square of pixels
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Image {
+    pub fn filled(width: usize, height: usize, colour: Colour) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![colour; width * height],
+        }
+    }
+
+    /// Missing cells are left black, an empty grid gives an empty image
+    pub fn from_grid<G, F>(grid: &G, palette: F, scale: usize) -> Self
+    where
+        G: GridLike,
+        F: Fn(&G::Cell) -> Colour,
+    {
+        let Some(surface) = grid.surface() else {
+            return Self::filled(0, 0, Colour::BLACK);
+        };
+        let mut image = Self::for_surface(surface, scale);
+
+        for (point, cell) in grid.cells() {
+            image.fill_cell(surface, &point, palette(cell), scale);
+        }
+
+        image
+    }
+
+    /// White points on black background, covering the bounding box of the points
+    #[allow(dead_code)]
+    pub fn from_points(points: &HashSet<Point>, scale: usize) -> Self {
+        let surface = bounding_box(points);
+
+        Self::from_points_in(points, surface, scale)
+    }
+
+    /// Like `from_points`, but for a known area, points outside it are skipped
+    pub fn from_points_in(points: &HashSet<Point>, surface: SurfaceRange, scale: usize) -> Self {
+        let mut image = Self::for_surface(surface, scale);
+
+        for point in points.iter().filter(|p| surface.contains(**p)) {
+            image.fill_cell(surface, point, Colour::WHITE, scale);
+        }
+
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Colour> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        Some(self.pixels[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: Colour) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    /// Binary PPM (P6), readable by most image viewers
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.rgb());
+
+        data
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        png::encode(self.width, self.height, &self.rgb())
+    }
+
+    /// Format is chosen by the extension, `ppm` or `png`
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let data = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            Some("png") => self.to_png()?,
+            _ => return Err(format!("Unsupported image format: {}", path.display())),
+        };
+
+        fs::write(path, data).map_err(|e| e.to_string())
+    }
+
+    fn for_surface(surface: SurfaceRange, scale: usize) -> Self {
+        Self::filled(
+            surface.columns().len() as usize * scale,
+            surface.rows().len() as usize * scale,
+            Colour::BLACK,
+        )
+    }
+
+    fn fill_cell(&mut self, surface: SurfaceRange, point: &Point, colour: Colour, scale: usize) {
+        let top_left = surface.top_left_corner();
+        let x = (point.x - top_left.x) as usize * scale;
+        let y = (point.y - top_left.y) as usize * scale;
+
+        for dy in 0..scale {
+            for dx in 0..scale {
+                self.set(x + dx, y + dy, colour);
+            }
+        }
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|c| [c.r, c.g, c.b]).collect()
+    }
+}
+
+fn bounding_box(points: &HashSet<Point>) -> SurfaceRange {
+    if points.is_empty() {
+        return SurfaceRange::rectangle(1, 1);
+    }
+
+    let min_x = points.iter().map(|p| p.x).min().unwrap();
+    let max_x = points.iter().map(|p| p.x).max().unwrap();
+    let min_y = points.iter().map(|p| p.y).min().unwrap();
+    let max_y = points.iter().map(|p| p.y).max().unwrap();
+
+    SurfaceRange::from_points(min_x, max_x, min_y, max_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::Grid;
+    use crate::utils::light_grid::LightGrid;
+    use crate::utils::point::Point;
+    use crate::utils::render::image::Image;
+    use crate::utils::render::Colour;
+    use std::collections::HashSet;
+
+    fn palette(c: &char) -> Colour {
+        match c {
+            '#' => Colour::WHITE,
+            'S' => Colour::RED,
+            _ => Colour::BLACK,
+        }
+    }
+
+    #[test]
+    fn from_grid_scaled() {
+        let grid: Grid<char> = Grid::from("#.\n.S");
+
+        let image = Image::from_grid(&grid, palette, 2);
+
+        assert_eq!(4, image.width());
+        assert_eq!(4, image.height());
+        assert_eq!(Some(Colour::WHITE), image.get(1, 1));
+        assert_eq!(Some(Colour::BLACK), image.get(2, 1));
+        assert_eq!(Some(Colour::RED), image.get(3, 3));
+        assert_eq!(None, image.get(4, 0));
+    }
+
+    #[test]
+    fn from_light_grid() {
+        let grid: LightGrid<char> = "#.\n.S".parse().unwrap();
+
+        let image = Image::from_grid(&grid, palette, 1);
+
+        assert_eq!(Some(Colour::RED), image.get(1, 1));
+    }
+
+    #[test]
+    fn from_empty_grid() {
+        let grid: LightGrid<char> = "".parse().unwrap();
+
+        let image = Image::from_grid(&grid, palette, 3);
+
+        assert_eq!((0, 0), (image.width(), image.height()));
+    }
+
+    #[test]
+    fn from_points() {
+        let points = HashSet::from([Point::new(-1, 5), Point::new(1, 6)]);
+
+        let image = Image::from_points(&points, 1);
+
+        assert_eq!((3, 2), (image.width(), image.height()));
+        assert_eq!(Some(Colour::WHITE), image.get(0, 0));
+        assert_eq!(Some(Colour::BLACK), image.get(1, 0));
+        assert_eq!(Some(Colour::WHITE), image.get(2, 1));
+
+        let empty = Image::from_points(&HashSet::new(), 2);
+        assert_eq!((2, 2), (empty.width(), empty.height()));
+    }
+
+    #[test]
+    fn to_ppm() {
+        let grid: Grid<char> = Grid::from("#S");
+
+        let ppm = Image::from_grid(&grid, palette, 1).to_ppm();
+
+        assert_eq!(b"P6\n2 1\n255\n\xff\xff\xff\xff\x00\x00".to_vec(), ppm);
+    }
+
+    #[test]
+    fn save_unknown_format() {
+        let image = Image::filled(1, 1, Colour::BLACK);
+
+        assert!(image.save(std::path::Path::new("image.gif")).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+pub mod ansi;
+pub mod frames;
+pub mod gif;
+pub mod image;
+mod png;
+
+/// RGB colour used by the renderers
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BLACK: Self = Self::new(0, 0, 0);
+    pub const WHITE: Self = Self::new(255, 255, 255);
+    pub const GREY: Self = Self::new(128, 128, 128);
+    pub const RED: Self = Self::new(255, 0, 0);
+    #[allow(dead_code)]
+    pub const GREEN: Self = Self::new(0, 255, 0);
+    #[allow(dead_code)]
+    pub const BLUE: Self = Self::new(0, 0, 255);
+    pub const YELLOW: Self = Self::new(255, 255, 0);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Nearest colour of the 6x6x6 cube in the 256-colour terminal palette
+    pub fn to_ansi256(self) -> u8 {
+        let level = |c: u8| ((c as u16 * 5 + 127) / 255) as u8;
+
+        16 + 36 * level(self.r) + 6 * level(self.g) + level(self.b)
+    }
+
+    /// Colour between black and white, used for numeric cells
+    pub fn gradient(value: usize, max: usize) -> Self {
+        let level = (value.min(max) * 255 / max.max(1)) as u8;
+
+        Self::new(level, level, level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::render::Colour;
+
+    #[test]
+    fn to_ansi256() {
+        assert_eq!(16, Colour::BLACK.to_ansi256());
+        assert_eq!(231, Colour::WHITE.to_ansi256());
+        assert_eq!(196, Colour::RED.to_ansi256());
+        assert_eq!(46, Colour::GREEN.to_ansi256());
+        assert_eq!(21, Colour::BLUE.to_ansi256());
+    }
+
+    #[test]
+    fn gradient() {
+        assert_eq!(Colour::BLACK, Colour::gradient(0, 9));
+        assert_eq!(Colour::WHITE, Colour::gradient(9, 9));
+        assert_eq!(Colour::new(85, 85, 85), Colour::gradient(3, 9));
+    }
+}
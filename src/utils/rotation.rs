@@ -1,4 +1,92 @@
+/// Turn on a grid in steps of 45 degrees, clockwise as seen on screen (y grows downwards)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Rotation {
+    None,
+    Clockwise45,
     Clockwise,
+    Clockwise135,
+    Reverse,
+    CounterClockwise135,
     CounterClockwise,
+    CounterClockwise45,
+}
+
+impl Rotation {
+    const ALL: [Self; 8] = [
+        Self::None,
+        Self::Clockwise45,
+        Self::Clockwise,
+        Self::Clockwise135,
+        Self::Reverse,
+        Self::CounterClockwise135,
+        Self::CounterClockwise,
+        Self::CounterClockwise45,
+    ];
+
+    /// Rotation by the given number of 45 degree steps, negative ones going counter-clockwise
+    pub fn from_eighths(steps: isize) -> Self {
+        Self::ALL[steps.rem_euclid(8) as usize]
+    }
+
+    /// Number of clockwise 45 degree steps, from 0 to 7
+    pub fn eighths(self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Clockwise45 => 1,
+            Self::Clockwise => 2,
+            Self::Clockwise135 => 3,
+            Self::Reverse => 4,
+            Self::CounterClockwise135 => 5,
+            Self::CounterClockwise => 6,
+            Self::CounterClockwise45 => 7,
+        }
+    }
+
+    /// Rotation doing self first and then the other one
+    pub fn then(self, other: Self) -> Self {
+        Self::from_eighths((self.eighths() + other.eighths()) as isize)
+    }
+
+    pub fn inverse(self) -> Self {
+        Self::from_eighths(-(self.eighths() as isize))
+    }
+
+    pub fn is_right_angle_multiple(self) -> bool {
+        self.eighths().is_multiple_of(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::rotation::Rotation;
+
+    #[test]
+    fn eighths() {
+        assert_eq!(Rotation::None, Rotation::from_eighths(8));
+        assert_eq!(Rotation::CounterClockwise, Rotation::from_eighths(-2));
+        assert_eq!(Rotation::Clockwise135, Rotation::from_eighths(-13));
+        assert_eq!(4, Rotation::Reverse.eighths());
+        assert!(Rotation::Reverse.is_right_angle_multiple());
+        assert!(!Rotation::CounterClockwise45.is_right_angle_multiple());
+    }
+
+    #[test]
+    fn composition_and_inverse() {
+        assert_eq!(
+            Rotation::Reverse,
+            Rotation::Clockwise.then(Rotation::Clockwise)
+        );
+        assert_eq!(
+            Rotation::Clockwise,
+            Rotation::Clockwise135.then(Rotation::CounterClockwise45)
+        );
+        assert_eq!(Rotation::CounterClockwise, Rotation::Clockwise.inverse());
+        assert_eq!(Rotation::Reverse, Rotation::Reverse.inverse());
+        assert_eq!(Rotation::None, Rotation::None.inverse());
+
+        for steps in 0..8 {
+            let rotation = Rotation::from_eighths(steps);
+            assert_eq!(Rotation::None, rotation.then(rotation.inverse()));
+        }
+    }
 }
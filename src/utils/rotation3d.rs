@@ -0,0 +1,220 @@
+use crate::utils::point3d::Point3D;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// Proper rotation of the 3D grid, one of the 24 ways to orient a cube.
+/// Kept as a matrix of -1, 0 and 1 with exactly one non zero value in every row and column.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rotation3D {
+    matrix: [[isize; 3]; 3],
+}
+
+impl Rotation3D {
+    #[allow(dead_code)]
+    pub const IDENTITY: Self = Self {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// Every axis permutation with every sign combination, keeping only those without mirroring
+    pub fn all() -> Vec<Self> {
+        (0..3)
+            .permutations(3)
+            .cartesian_product([1, -1].into_iter().cartesian_product([1, -1]))
+            .cartesian_product([1, -1])
+            .map(|((axes, (x_sign, y_sign)), z_sign)| {
+                let signs = [x_sign, y_sign, z_sign];
+                let mut matrix = [[0; 3]; 3];
+                for row in 0..3 {
+                    matrix[row][axes[row]] = signs[row];
+                }
+
+                Self { matrix }
+            })
+            .filter(|rotation| rotation.determinant() == 1)
+            .collect()
+    }
+
+    /// Quarter turn counter-clockwise around the X axis, looking from positive X
+    #[allow(dead_code)]
+    pub fn around_x() -> Self {
+        Self {
+            matrix: [[1, 0, 0], [0, 0, -1], [0, 1, 0]],
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn around_y() -> Self {
+        Self {
+            matrix: [[0, 0, 1], [0, 1, 0], [-1, 0, 0]],
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn around_z() -> Self {
+        Self {
+            matrix: [[0, -1, 0], [1, 0, 0], [0, 0, 1]],
+        }
+    }
+
+    pub fn apply(&self, point: &Point3D) -> Point3D {
+        let [x, y, z] = self
+            .matrix
+            .map(|row| row[0] * point.x + row[1] * point.y + row[2] * point.z);
+
+        Point3D::new(x, y, z)
+    }
+
+    /// Rotation doing self first and then the other one
+    #[allow(dead_code)]
+    pub fn then(&self, other: &Self) -> Self {
+        let mut matrix = [[0; 3]; 3];
+        for (row, values) in matrix.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = (0..3)
+                    .map(|k| other.matrix[row][k] * self.matrix[k][column])
+                    .sum();
+            }
+        }
+
+        Self { matrix }
+    }
+
+    /// Transposed matrix, as rotation matrices are orthogonal
+    #[allow(dead_code)]
+    pub fn inverse(&self) -> Self {
+        let mut matrix = [[0; 3]; 3];
+        for (row, values) in matrix.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = self.matrix[column][row];
+            }
+        }
+
+        Self { matrix }
+    }
+
+    fn determinant(&self) -> isize {
+        let m = self.matrix;
+
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+/// Rotation followed by translation, mapping points of one frame to another
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    pub rotation: Rotation3D,
+    pub translation: Point3D,
+}
+
+impl Alignment {
+    #[allow(dead_code)]
+    pub fn apply(&self, point: &Point3D) -> Point3D {
+        self.rotation.apply(point) + self.translation
+    }
+
+    /// Finds how to place `other` in the frame of `reference`, so that at least
+    /// `min_matches` points land on reference points. Every rotation votes for translations
+    /// between all pairs of points, like aligning scanners seeing the same beacons.
+    #[allow(dead_code)]
+    pub fn find(reference: &[Point3D], other: &[Point3D], min_matches: usize) -> Option<Self> {
+        Rotation3D::all().into_iter().find_map(|rotation| {
+            let rotated: Vec<Point3D> = other.iter().map(|p| rotation.apply(p)).collect();
+            let mut votes: HashMap<Point3D, usize> = HashMap::new();
+
+            for r in reference {
+                for o in &rotated {
+                    let count = votes.entry(*r - *o).or_default();
+                    *count += 1;
+
+                    if *count >= min_matches {
+                        return Some(Self {
+                            rotation,
+                            translation: *r - *o,
+                        });
+                    }
+                }
+            }
+
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::point3d::Point3D;
+    use crate::utils::rotation3d::{Alignment, Rotation3D};
+    use itertools::Itertools;
+
+    #[test]
+    fn there_are_24_rotations() {
+        let all = Rotation3D::all();
+
+        assert_eq!(24, all.len());
+        assert_eq!(24, all.iter().unique().count());
+        assert!(all.contains(&Rotation3D::IDENTITY));
+        assert!(all
+            .iter()
+            .cartesian_product(all.iter())
+            .all(|(a, b)| all.contains(&a.then(b))));
+    }
+
+    #[test]
+    fn quarter_turns() {
+        let point = Point3D::new(1, 2, 3);
+
+        assert_eq!(Point3D::new(1, -3, 2), Rotation3D::around_x().apply(&point));
+        assert_eq!(Point3D::new(3, 2, -1), Rotation3D::around_y().apply(&point));
+        assert_eq!(Point3D::new(-2, 1, 3), Rotation3D::around_z().apply(&point));
+
+        let full_turn = (0..4).fold(Rotation3D::IDENTITY, |r, _| r.then(&Rotation3D::around_z()));
+        assert_eq!(Rotation3D::IDENTITY, full_turn);
+    }
+
+    #[test]
+    fn composition_and_inverse() {
+        let point = Point3D::new(4, -5, 6);
+        let (x, y) = (Rotation3D::around_x(), Rotation3D::around_y());
+
+        assert_eq!(y.apply(&x.apply(&point)), x.then(&y).apply(&point));
+        assert_ne!(x.then(&y), y.then(&x));
+
+        for rotation in Rotation3D::all() {
+            assert_eq!(Rotation3D::IDENTITY, rotation.then(&rotation.inverse()));
+            assert_eq!(point, rotation.inverse().apply(&rotation.apply(&point)));
+        }
+    }
+
+    #[test]
+    fn finds_alignment_of_scanners() {
+        let beacons = [
+            Point3D::new(404, -588, -901),
+            Point3D::new(528, -643, 409),
+            Point3D::new(-838, 591, 734),
+            Point3D::new(390, -675, -793),
+            Point3D::new(-537, -823, -458),
+            Point3D::new(-485, -357, 347),
+            Point3D::new(-345, -311, 381),
+        ];
+        let rotation = Rotation3D::around_x().then(&Rotation3D::around_z());
+        let translation = Point3D::new(68, -1246, -43);
+
+        // second scanner sees the same beacons from its own position and orientation
+        let seen: Vec<Point3D> = beacons
+            .iter()
+            .map(|b| rotation.inverse().apply(&(*b - translation)))
+            .collect();
+
+        let alignment = Alignment::find(&beacons, &seen, 6).unwrap();
+
+        assert_eq!(rotation, alignment.rotation);
+        assert_eq!(translation, alignment.translation);
+        assert!(seen
+            .iter()
+            .zip(beacons.iter())
+            .all(|(s, b)| alignment.apply(s) == *b));
+        assert_eq!(None, Alignment::find(&beacons, &seen[..5], 6));
+    }
+}
@@ -42,10 +42,7 @@ impl Vector {
 
     #[inline]
     pub fn rotate(&self, rotation: Rotation) -> Self {
-        match rotation {
-            Rotation::Clockwise => self.rotate_cw(),
-            Rotation::CounterClockwise => self.rotate_ccw(),
-        }
+        Self::new(self.position, self.facing.rotate(rotation))
     }
 
     #[inline]
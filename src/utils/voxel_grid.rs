@@ -0,0 +1,527 @@
+use crate::utils::grid::Grid;
+use crate::utils::point::Point;
+use crate::utils::point3d::Point3D;
+use crate::utils::surface_range::SurfaceRange;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Inclusive axis-aligned bounding box of voxels
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VoxelBounds {
+    pub min: Point3D,
+    pub max: Point3D,
+}
+
+impl VoxelBounds {
+    pub fn new(min: Point3D, max: Point3D) -> Self {
+        assert!(
+            min.x <= max.x && min.y <= max.y && min.z <= max.z,
+            "Bounds min {} is above max {}",
+            min,
+            max
+        );
+
+        Self { min, max }
+    }
+
+    /// None for no points
+    pub fn from_points<'a, I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Point3D>,
+    {
+        points.into_iter().fold(None, |bounds, p| {
+            Some(match bounds {
+                None => Self { min: *p, max: *p },
+                Some(Self { min, max }) => Self {
+                    min: Point3D::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                    max: Point3D::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+                },
+            })
+        })
+    }
+
+    pub fn contains(&self, point: &Point3D) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    /// Bounds extended by `by` in every direction
+    pub fn grow(&self, by: isize) -> Self {
+        let offset = Point3D::new(by, by, by);
+
+        Self::new(self.min - offset, self.max + offset)
+    }
+
+    pub fn volume(&self) -> usize {
+        let size = self.max - self.min + Point3D::new(1, 1, 1);
+
+        (size.x * size.y * size.z) as usize
+    }
+
+    /// All points ordered by z, then y, then x
+    pub fn points(&self) -> impl Iterator<Item = Point3D> {
+        let Self { min, max } = *self;
+
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3D::new(x, y, z)))
+        })
+    }
+}
+
+/// Common interface of voxel storages, 3D algorithms are written once against it
+pub trait VoxelLike {
+    type Voxel;
+
+    fn get(&self, point: &Point3D) -> Option<&Self::Voxel>;
+
+    /// Occupied voxels in no particular order
+    fn voxels(&self) -> impl Iterator<Item = (Point3D, &Self::Voxel)>;
+
+    fn contains(&self, point: &Point3D) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// Occupied neighbours sharing a face
+    #[allow(dead_code)]
+    fn neighbours(&self, point: &Point3D) -> impl Iterator<Item = Point3D> {
+        point.adjacent().into_iter().filter(|p| self.contains(p))
+    }
+
+    /// Occupied neighbours sharing a face, an edge or a corner
+    #[allow(dead_code)]
+    fn neighbours_with_diagonals(&self, point: &Point3D) -> impl Iterator<Item = Point3D> {
+        point
+            .adjacent_with_diagonals()
+            .into_iter()
+            .filter(|p| self.contains(p))
+    }
+
+    /// Bounding box of occupied voxels, None for an empty grid
+    fn bounds(&self) -> Option<VoxelBounds> {
+        let points: Vec<Point3D> = self.voxels().map(|(p, _)| p).collect();
+
+        VoxelBounds::from_points(&points)
+    }
+
+    /// Breadth-first search over face neighbours, it never leaves `within`.
+    /// `passable` gets the voxel value or None for an empty point.
+    fn flood_fill<F>(&self, start: Point3D, within: VoxelBounds, passable: F) -> HashSet<Point3D>
+    where
+        F: Fn(&Point3D, Option<&Self::Voxel>) -> bool,
+    {
+        let mut visited: HashSet<Point3D> = HashSet::new();
+        if !within.contains(&start) || !passable(&start, self.get(&start)) {
+            return visited;
+        }
+
+        let mut queue: VecDeque<Point3D> = VecDeque::from([start]);
+        visited.insert(start);
+
+        while let Some(point) = queue.pop_front() {
+            for next in point.adjacent() {
+                if within.contains(&next)
+                    && !visited.contains(&next)
+                    && passable(&next, self.get(&next))
+                {
+                    visited.insert(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Empty points reachable from outside, within the bounds grown by one
+    fn exterior(&self) -> HashSet<Point3D> {
+        let Some(bounds) = self.bounds() else {
+            return HashSet::new();
+        };
+        let bounds = bounds.grow(1);
+
+        self.flood_fill(bounds.min, bounds, |_, voxel| voxel.is_none())
+    }
+
+    /// Number of voxel faces not touching another voxel, air pockets inside included
+    #[allow(dead_code)]
+    fn surface_area(&self) -> usize {
+        self.voxels()
+            .flat_map(|(p, _)| p.adjacent())
+            .filter(|p| !self.contains(p))
+            .count()
+    }
+
+    /// Number of voxel faces reachable from outside, air pockets inside are not counted
+    #[allow(dead_code)]
+    fn exterior_surface_area(&self) -> usize {
+        let exterior = self.exterior();
+
+        self.voxels()
+            .flat_map(|(p, _)| p.adjacent())
+            .filter(|p| exterior.contains(p))
+            .count()
+    }
+
+    /// How many levels the shape can fall down before it rests on a voxel or reaches `floor` z.
+    /// Voxels of the shape itself do not block it, so it can be already inserted.
+    fn drop_distance(&self, shape: &[Point3D], floor: isize) -> isize {
+        let lowest = shape.iter().map(|p| p.z).min().unwrap_or(floor);
+        let shape_points: HashSet<&Point3D> = shape.iter().collect();
+
+        (1..=lowest - floor)
+            .find(|distance| {
+                shape.iter().any(|p| {
+                    let below = Point3D::new(p.x, p.y, p.z - distance);
+
+                    self.contains(&below) && !shape_points.contains(&below)
+                })
+            })
+            .map_or(lowest - floor, |distance| distance - 1)
+    }
+
+    /// Voxels directly under the shape, which do not belong to it
+    #[allow(dead_code)]
+    fn supporters(&self, shape: &[Point3D]) -> Vec<(Point3D, &Self::Voxel)> {
+        let shape_points: HashSet<&Point3D> = shape.iter().collect();
+
+        shape
+            .iter()
+            .map(|p| p.down())
+            .filter(|below| !shape_points.contains(below))
+            .filter_map(|below| self.get(&below).map(|value| (below, value)))
+            .collect()
+    }
+
+    /// Level `z` viewed from above within the x and y bounds of the whole grid,
+    /// empty points are drawn as `.`. None for an empty grid.
+    #[allow(dead_code)]
+    fn slice_z<F>(&self, z: isize, to_char: F) -> Option<Grid<char>>
+    where
+        F: Fn(&Self::Voxel) -> char,
+    {
+        let bounds = self.bounds()?;
+        let surface =
+            SurfaceRange::from_points(bounds.min.x, bounds.max.x, bounds.min.y, bounds.max.y);
+
+        let mut grid = Grid::filled(surface, '.');
+        for (point, value) in self.voxels().filter(|(p, _)| p.z == z) {
+            grid.modify(Point::new(point.x, point.y), to_char(value));
+        }
+
+        Some(grid)
+    }
+}
+
+/// Sparse 3D grid, only occupied voxels are stored
+#[derive(Debug, Clone)]
+pub struct VoxelGrid<T> {
+    voxels: HashMap<Point3D, T>,
+}
+
+impl<T> VoxelGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            voxels: HashMap::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn insert(&mut self, point: Point3D, value: T) -> Option<T> {
+        self.voxels.insert(point, value)
+    }
+
+    #[allow(dead_code)]
+    pub fn remove(&mut self, point: &Point3D) -> Option<T> {
+        self.voxels.remove(point)
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.voxels.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.voxels.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = (&Point3D, &T)> {
+        self.voxels.iter()
+    }
+
+    #[allow(dead_code)]
+    pub fn points(&self) -> impl Iterator<Item = &Point3D> {
+        self.voxels.keys()
+    }
+}
+
+impl<T> VoxelLike for VoxelGrid<T> {
+    type Voxel = T;
+
+    fn get(&self, point: &Point3D) -> Option<&T> {
+        self.voxels.get(point)
+    }
+
+    fn voxels(&self) -> impl Iterator<Item = (Point3D, &T)> {
+        self.voxels.iter().map(|(p, value)| (*p, value))
+    }
+
+    fn bounds(&self) -> Option<VoxelBounds> {
+        VoxelBounds::from_points(self.voxels.keys())
+    }
+}
+
+/// Dense 3D grid covering fixed bounds, every point inside has a slot even when empty.
+/// Lookups are index arithmetic instead of hashing, which suits shapes filling most of their bounds.
+#[derive(Debug, Clone)]
+pub struct DenseVoxelGrid<T> {
+    extent: VoxelBounds,
+    cells: Vec<Option<T>>,
+    len: usize,
+}
+
+impl<T> DenseVoxelGrid<T> {
+    pub fn new(extent: VoxelBounds) -> Self {
+        Self {
+            extent,
+            cells: (0..extent.volume()).map(|_| None).collect(),
+            len: 0,
+        }
+    }
+
+    /// Bounds given at creation, voxels can only be stored inside them
+    #[allow(dead_code)]
+    pub fn extent(&self) -> VoxelBounds {
+        self.extent
+    }
+
+    /// Panics for points outside of the extent
+    pub fn insert(&mut self, point: Point3D, value: T) -> Option<T> {
+        let index = self
+            .index(&point)
+            .unwrap_or_else(|| panic!("Point {} is outside of the dense grid", point));
+        let previous = self.cells[index].replace(value);
+
+        if previous.is_none() {
+            self.len += 1;
+        }
+
+        previous
+    }
+
+    #[allow(dead_code)]
+    pub fn remove(&mut self, point: &Point3D) -> Option<T> {
+        let removed = self.index(point).and_then(|i| self.cells[i].take());
+
+        if removed.is_some() {
+            self.len -= 1;
+        }
+
+        removed
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn index(&self, point: &Point3D) -> Option<usize> {
+        if !self.extent.contains(point) {
+            return None;
+        }
+
+        let size = self.extent.max - self.extent.min + Point3D::new(1, 1, 1);
+        let offset = *point - self.extent.min;
+
+        Some((offset.x + offset.y * size.x + offset.z * size.x * size.y) as usize)
+    }
+}
+
+impl<T> VoxelLike for DenseVoxelGrid<T> {
+    type Voxel = T;
+
+    fn get(&self, point: &Point3D) -> Option<&T> {
+        self.index(point).and_then(|i| self.cells[i].as_ref())
+    }
+
+    fn voxels(&self) -> impl Iterator<Item = (Point3D, &T)> {
+        self.extent
+            .points()
+            .zip(self.cells.iter())
+            .filter_map(|(p, cell)| cell.as_ref().map(|value| (p, value)))
+    }
+}
+
+/// Extent is the bounding box of the collected voxels, just the origin when there are none
+impl<T> FromIterator<(Point3D, T)> for DenseVoxelGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point3D, T)>>(iter: I) -> Self {
+        let voxels: Vec<(Point3D, T)> = iter.into_iter().collect();
+        let extent = VoxelBounds::from_points(voxels.iter().map(|(p, _)| p)).unwrap_or(
+            VoxelBounds::new(Point3D::new(0, 0, 0), Point3D::new(0, 0, 0)),
+        );
+
+        let mut grid = Self::new(extent);
+        for (point, value) in voxels {
+            grid.insert(point, value);
+        }
+
+        grid
+    }
+}
+
+impl<T> Default for VoxelGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point3D, T)> for VoxelGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point3D, T)>>(iter: I) -> Self {
+        Self {
+            voxels: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Point3D, T)> for VoxelGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point3D, T)>>(&mut self, iter: I) {
+        self.voxels.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::point3d::Point3D;
+    use crate::utils::voxel_grid::{DenseVoxelGrid, VoxelBounds, VoxelGrid, VoxelLike};
+
+    const DROPLET: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    fn droplet<V: FromIterator<(Point3D, char)>>() -> V {
+        DROPLET
+            .lines()
+            .map(|line| (Point3D::from(line), '#'))
+            .collect()
+    }
+
+    #[test]
+    fn surface_area() {
+        let droplet: VoxelGrid<char> = droplet();
+
+        assert_eq!(64, droplet.surface_area());
+        assert_eq!(58, droplet.exterior_surface_area());
+    }
+
+    #[test]
+    fn dense_grid() {
+        let dense: DenseVoxelGrid<char> = droplet();
+
+        assert_eq!(13, dense.len());
+        assert_eq!(dense.extent(), dense.bounds().unwrap());
+        assert_eq!(64, dense.surface_area());
+        assert_eq!(58, dense.exterior_surface_area());
+        assert_eq!(
+            ".#.\n#.#\n.#.\n",
+            dense.slice_z(5, |c| *c).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn dense_grid_insert_and_remove() {
+        let mut dense: DenseVoxelGrid<u8> = DenseVoxelGrid::new(VoxelBounds::new(
+            Point3D::new(-1, -1, -1),
+            Point3D::new(1, 1, 1),
+        ));
+
+        assert_eq!(None, dense.bounds());
+        assert_eq!(None, dense.insert(Point3D::new(-1, 0, 1), 1));
+        assert_eq!(Some(1), dense.insert(Point3D::new(-1, 0, 1), 2));
+        assert_eq!(1, dense.len());
+        assert_eq!(Some(&2), dense.get(&Point3D::new(-1, 0, 1)));
+        assert_eq!(None, dense.get(&Point3D::new(5, 0, 0)));
+        assert_eq!(None, dense.remove(&Point3D::new(5, 0, 0)));
+        assert_eq!(Some(2), dense.remove(&Point3D::new(-1, 0, 1)));
+        assert!(dense.is_empty());
+    }
+
+    #[test]
+    fn bounds() {
+        let bounds = droplet::<VoxelGrid<char>>().bounds().unwrap();
+
+        assert_eq!(
+            VoxelBounds::new(Point3D::new(1, 1, 1), Point3D::new(3, 3, 6)),
+            bounds
+        );
+        assert_eq!(54, bounds.volume());
+        assert_eq!(54, bounds.points().count());
+        assert_eq!(200, bounds.grow(1).volume());
+        assert_eq!(None, VoxelGrid::<char>::new().bounds());
+    }
+
+    #[test]
+    fn exterior_skips_air_pocket() {
+        let droplet: VoxelGrid<char> = droplet();
+        let exterior = droplet.exterior();
+
+        assert!(!exterior.contains(&Point3D::new(2, 2, 5)));
+        assert!(exterior.contains(&Point3D::new(0, 0, 0)));
+        assert_eq!(200 - 13 - 1, exterior.len());
+    }
+
+    #[test]
+    fn drop_distance() {
+        let mut voxels: VoxelGrid<usize> = VoxelGrid::new();
+        voxels.extend([(Point3D::new(0, 0, 1), 1), (Point3D::new(1, 0, 1), 1)]);
+
+        let above = [Point3D::new(1, 0, 5), Point3D::new(2, 0, 5)];
+        assert_eq!(3, voxels.drop_distance(&above, 1));
+
+        let aside = [Point3D::new(2, 0, 5), Point3D::new(2, 0, 6)];
+        assert_eq!(4, voxels.drop_distance(&aside, 1));
+
+        let resting = [Point3D::new(0, 0, 2), Point3D::new(0, 0, 3)];
+        assert_eq!(0, voxels.drop_distance(&resting, 1));
+        assert_eq!(
+            vec![(Point3D::new(0, 0, 1), &1)],
+            voxels.supporters(&resting)
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let droplet: VoxelGrid<char> = droplet();
+        let center = Point3D::new(2, 2, 2);
+
+        assert_eq!(6, droplet.neighbours(&center).count());
+        assert_eq!(6, droplet.neighbours_with_diagonals(&center).count());
+
+        let below_pocket = Point3D::new(2, 2, 4);
+        assert_eq!(1, droplet.neighbours(&below_pocket).count());
+        assert_eq!(5, droplet.neighbours_with_diagonals(&below_pocket).count());
+        assert_eq!(6, droplet.neighbours(&Point3D::new(2, 2, 5)).count());
+    }
+
+    #[test]
+    fn slice_z() {
+        let slice = droplet::<VoxelGrid<char>>().slice_z(5, |c| *c).unwrap();
+
+        assert_eq!(".#.\n#.#\n.#.\n", slice.to_string());
+    }
+}
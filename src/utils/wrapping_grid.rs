@@ -0,0 +1,181 @@
+use crate::utils::direction::Direction;
+use crate::utils::grid_like::{GridLike, GridView};
+use crate::utils::point::Point;
+use crate::utils::surface_range::SurfaceRange;
+
+/// Infinite plane covered by copies of the base grid, every point maps into the base tile.
+/// Neighbours are never wrapped, so searches can walk away from the base tile.
+pub struct InfiniteGrid<'a, G> {
+    base: &'a G,
+    surface: SurfaceRange,
+}
+
+impl<'a, G> InfiniteGrid<'a, G>
+where
+    G: GridLike,
+{
+    /// Panics for an empty base grid, there is nothing to repeat
+    pub fn new(base: &'a G) -> Self {
+        Self {
+            base,
+            surface: base.surface().expect("Cannot repeat an empty grid"),
+        }
+    }
+
+    /// Position of the given point inside the base tile
+    pub fn wrap(&self, point: &Point) -> Point {
+        wrap(self.surface, point)
+    }
+
+    /// Which copy of the base tile the point is in, base tile is (0, 0)
+    #[allow(dead_code)]
+    pub fn tile(&self, point: &Point) -> Point {
+        let top_left = self.surface.top_left_corner();
+
+        Point::new(
+            (point.x - top_left.x).div_euclid(self.surface.columns().len()),
+            (point.y - top_left.y).div_euclid(self.surface.rows().len()),
+        )
+    }
+}
+
+impl<G> GridView for InfiniteGrid<'_, G>
+where
+    G: GridLike,
+{
+    type Cell = G::Cell;
+
+    fn get_for_point(&self, point: &Point) -> Option<&Self::Cell> {
+        self.base.get_for_point(&self.wrap(point))
+    }
+}
+
+/// Base grid with opposite edges glued together, so neighbours wrap around the edges
+#[allow(dead_code)]
+pub struct ToroidalGrid<'a, G> {
+    base: &'a G,
+    surface: SurfaceRange,
+}
+
+impl<'a, G> ToroidalGrid<'a, G>
+where
+    G: GridLike,
+{
+    /// Panics for an empty base grid, there are no edges to glue
+    #[allow(dead_code)]
+    pub fn new(base: &'a G) -> Self {
+        Self {
+            base,
+            surface: base.surface().expect("Cannot wrap an empty grid"),
+        }
+    }
+
+    /// Position of the given point inside the base grid
+    pub fn wrap(&self, point: &Point) -> Point {
+        wrap(self.surface, point)
+    }
+}
+
+impl<G> GridView for ToroidalGrid<'_, G>
+where
+    G: GridLike,
+{
+    type Cell = G::Cell;
+
+    fn get_for_point(&self, point: &Point) -> Option<&Self::Cell> {
+        self.base.get_for_point(&self.wrap(point))
+    }
+
+    fn step(&self, point: &Point, direction: Direction) -> Option<Point> {
+        let next = self.wrap(&point.move_in(direction));
+
+        self.base.get_for_point(&next).map(|_| next)
+    }
+}
+
+fn wrap(surface: SurfaceRange, point: &Point) -> Point {
+    let top_left = surface.top_left_corner();
+
+    Point::new(
+        top_left.x + (point.x - top_left.x).rem_euclid(surface.columns().len()),
+        top_left.y + (point.y - top_left.y).rem_euclid(surface.rows().len()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::grid::Grid;
+    use crate::utils::grid_like::GridView;
+    use crate::utils::point::Point;
+    use crate::utils::wrapping_grid::{InfiniteGrid, ToroidalGrid};
+
+    const EXAMPLE: &str = r#"AB.
+C#.
+..."#;
+
+    #[test]
+    fn infinite_get() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let infinite = InfiniteGrid::new(&grid);
+
+        assert_eq!(Some(&'A'), infinite.get_for_point(&Point::new(3, 3)));
+        assert_eq!(Some(&'A'), infinite.get_for_point(&Point::new(-3, -6)));
+        assert_eq!(Some(&'B'), infinite.get_for_point(&Point::new(-2, 0)));
+        assert_eq!(Some(&'#'), infinite.get_for_point(&Point::new(1, -2)));
+    }
+
+    #[test]
+    fn infinite_tile() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let infinite = InfiniteGrid::new(&grid);
+
+        assert_eq!(Point::new(0, 0), infinite.tile(&Point::new(2, 2)));
+        assert_eq!(Point::new(1, 0), infinite.tile(&Point::new(3, 2)));
+        assert_eq!(Point::new(-1, -1), infinite.tile(&Point::new(-1, -3)));
+    }
+
+    #[test]
+    fn infinite_flood_fill() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let infinite = InfiniteGrid::new(&grid);
+        let start = Point::new(0, 0);
+
+        let reached =
+            infinite.flood_fill(start, |p, &c| c != '#' && p.manhattan_distance(&start) <= 4);
+
+        assert!(reached.contains(&Point::new(-4, 0)));
+        assert!(reached.contains(&Point::new(0, 4)));
+        assert!(!reached.contains(&Point::new(1, 1)));
+        assert!(!reached.contains(&Point::new(-2, -2)));
+    }
+
+    #[test]
+    fn toroidal_neighbours() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let torus = ToroidalGrid::new(&grid);
+
+        let mut neighbours: Vec<Point> = torus.neighbours(&Point::new(0, 0)).collect();
+        neighbours.sort();
+
+        assert_eq!(
+            vec![
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(1, 0),
+                Point::new(2, 0)
+            ],
+            neighbours
+        );
+    }
+
+    #[test]
+    fn toroidal_flood_fill() {
+        let grid: Grid<char> = Grid::from(EXAMPLE);
+        let torus = ToroidalGrid::new(&grid);
+
+        let reached = torus.flood_fill(Point::new(2, 2), |_, &c| c == '.' || c == 'A');
+
+        assert_eq!(6, reached.len());
+        assert!(reached.contains(&Point::new(0, 0)));
+    }
+}